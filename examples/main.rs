@@ -7,7 +7,11 @@ use bevy::{
     },
     prelude::*,
 };
-use bevy_adventure::prelude::*;
+use bevy_adventure::{
+    prelude::*,
+    ItemRef,
+    NoState,
+};
 use bevy_inspector_egui::quick::WorldInspectorPlugin;
 use bevy_rapier3d::prelude::*;
 
@@ -321,9 +325,44 @@ fn setup_audio(mut server: AudioServer) {
         .load(SFX_DOOR_ENTER);
 }
 
-fn print_messages(mut messages: EventReader<NewMessage>) {
+const ITEM_FLASHLIGHT: &str = "Flashlight";
+
+fn setup_items(mut registry: ResMut<ItemRegistry>, mut behaviors: ResMut<ItemBehaviors>) {
+    registry
+        .insert(
+            ITEM_FLASHLIGHT_EMPTY,
+            ItemInfo::new("Flashlight").description("It doesn't have any batteries."),
+        )
+        .insert(
+            ITEM_FLASHLIGHT,
+            ItemInfo::new("Flashlight").description("It's working now."),
+        )
+        .insert(
+            ITEM_BATTERIES,
+            ItemInfo::new("Batteries").description("A pair of AA batteries."),
+        );
+
+    behaviors.insert(
+        ITEM_FLASHLIGHT_EMPTY,
+        |other: &mut ItemRef, state: &mut ResMut<WorldState>| -> Vec<Action<NoState>> {
+            if other.as_str() != ITEM_BATTERIES {
+                return Vec::new();
+            }
+
+            other.consume();
+            state.insert("flashlight", true);
+
+            vec![
+                Action::RemoveItem(Item::new(ITEM_FLASHLIGHT_EMPTY)),
+                Item::new(ITEM_FLASHLIGHT).into(),
+            ]
+        },
+    );
+}
+
+fn print_messages(mut messages: EventReader<NewMessage>, registry: Res<ItemRegistry>) {
     for message in messages.read() {
-        println!("Message: {:?}", message);
+        println!("Message: {}", message.0.text(&registry));
     }
 }
 
@@ -347,7 +386,7 @@ fn main() {
         .add_scene::<BedroomScene>()
         .add_scene::<HallwayScene>()
         ////
-        .add_systems(Startup, setup_audio)
+        .add_systems(Startup, (setup_audio, setup_items))
         ////
         .add_systems(Update, print_messages)
         ////
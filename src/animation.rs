@@ -13,11 +13,17 @@ impl Plugin for AnimationPlugin {
     fn build(&self, app: &mut App) {
         app ////
             .register_type::<Tween<Transform>>()
+            .register_type::<Tween<Projection>>()
+            .register_type::<TweenPath>()
+            .register_type::<Easing>()
             ////
             .init_resource::<AnimationRegistry>()
             .init_resource::<AnimationQueue>()
             ////
-            .add_systems(Update, (tween_transforms, play_animations));
+            .add_systems(
+                Update,
+                (tween_transforms, tween_projections, play_animations),
+            );
     }
 }
 
@@ -26,31 +32,284 @@ impl Plugin for AnimationPlugin {
 #[reflect(Component)]
 pub struct Tween<T: Default> {
     pub target: T,
+    pub start: T,
+    pub elapsed: f32,
+    pub duration: f32,
+    pub easing: Easing,
 }
 
-impl<T: Default> Tween<T> {
+impl<T: Default + Clone> Tween<T> {
     pub fn new(target: T) -> Self {
-        Self { target }
+        Self {
+            start: target.clone(),
+            target,
+            elapsed: 0.0,
+            duration: 0.0,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Start animating from `start` to `target` over `duration` seconds.
+    pub fn animate(&mut self, start: T, target: T, duration: f32, easing: Easing) {
+        self.start = start;
+        self.target = target;
+        self.elapsed = 0.0;
+        self.duration = duration;
+        self.easing = easing;
+    }
+
+    /// Jump straight to `target`, without animating.
+    pub fn jump(&mut self, target: T) {
+        self.animate(target.clone(), target, 0.0, self.easing);
+    }
+
+    /// Returns how far along the animation is, from 0 to 1, before easing.
+    pub fn progress(&self) -> f32 {
+        if self.duration > 0.0 {
+            (self.elapsed / self.duration).min(1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// Returns true once the animation has reached its target.
+    pub fn finished(&self) -> bool {
+        self.progress() >= 1.0
+    }
+}
+
+impl Tween<Transform> {
+    /// Returns the transform at the current point of the animation, following the path if there is one.
+    ///
+    /// Only the translation and the rotation are animated.
+    pub fn current(&self, path: Option<&TweenPath>) -> Transform {
+        let t = self.easing.apply(self.progress());
+
+        let translation = match path {
+            Some(path) => path.sample(self.start.translation, self.target.translation, t),
+            None => self.start.translation.lerp(self.target.translation, t),
+        };
+
+        Transform {
+            translation,
+            rotation: self.start.rotation.slerp(self.target.rotation, t),
+            scale: self.target.scale,
+        }
+    }
+}
+
+/// A component that makes a `Tween<Transform>` move along a spline through the given points,
+/// instead of in a straight line.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct TweenPath {
+    /// The points between the start and the target of the animation, in order.
+    pub points: Vec<Vec3>,
+}
+
+impl TweenPath {
+    /// Returns a new `TweenPath` through the given points.
+    pub fn new(points: Vec<Vec3>) -> Self {
+        Self { points }
+    }
+
+    /// Returns the position along the spline from `start`, through the points, to `end`, for `t` from 0 to 1.
+    ///
+    /// `t` is spread over the segments by their length, so the speed stays roughly constant.
+    pub fn sample(&self, start: Vec3, end: Vec3, t: f32) -> Vec3 {
+        let mut knots = Vec::with_capacity(self.points.len() + 2);
+        knots.push(start);
+        knots.extend(&self.points);
+        knots.push(end);
+
+        let lengths = knots
+            .windows(2)
+            .map(|pair| pair[0].distance(pair[1]))
+            .collect::<Vec<_>>();
+
+        let total = lengths.iter().sum::<f32>();
+
+        if total <= f32::EPSILON {
+            return end;
+        }
+
+        let last = lengths.len() - 1;
+        let mut distance = t.clamp(0.0, 1.0) * total;
+
+        for (i, length) in lengths.iter().enumerate() {
+            if distance <= *length || i == last {
+                let local = if *length > 0.0 {
+                    (distance / length).min(1.0)
+                } else {
+                    1.0
+                };
+
+                return catmull_rom(
+                    knots[i.saturating_sub(1)],
+                    knots[i],
+                    knots[i + 1],
+                    knots[(i + 2).min(knots.len() - 1)],
+                    local,
+                );
+            }
+
+            distance -= length;
+        }
+
+        end
+    }
+}
+
+/// Returns the point at `t` on the Catmull-Rom segment between `p1` and `p2`.
+fn catmull_rom(p0: Vec3, p1: Vec3, p2: Vec3, p3: Vec3, t: f32) -> Vec3 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    0.5 * ((2.0 * p1)
+        + (p2 - p0) * t
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+        + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3)
+}
+
+/// An easing function, which shapes how the camera accelerates and decelerates when moving between `CameraSpot`s.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, Default, Reflect)]
+#[reflect_value(Debug, Default)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+
+    /// Smoothly accelerate and decelerate.
+    #[default]
+    EaseInOut,
+
+    /// Accelerate and decelerate more sharply than `EaseInOut`.
+    Cubic,
+
+    /// A custom function, mapping progress from 0 to 1 to an eased value (usually also from 0 to 1).
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+    /// Returns the eased value for the given progress, from 0 to 1.
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Self::Linear => t,
+            Self::EaseInOut => t * t * (3.0 - 2.0 * t),
+            Self::Cubic => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (2.0 - 2.0 * t).powi(3) / 2.0
+                }
+            }
+            Self::Custom(f) => f(t),
+        }
+    }
+
+    /// Returns the easing with the given name (`"linear"`, `"ease_in_out"` or `"cubic"`), if any.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().replace('-', "_").as_str() {
+            "linear" => Some(Self::Linear),
+            "ease_in_out" | "easeinout" => Some(Self::EaseInOut),
+            "cubic" => Some(Self::Cubic),
+            _ => None,
+        }
     }
 }
 
 #[allow(clippy::needless_pass_by_value)]
-fn tween_transforms(time: Res<Time>, mut targets: Query<(&Tween<Transform>, &mut Transform)>) {
+pub(crate) fn tween_transforms(
+    time: Res<Time>,
+    mut targets: Query<(&mut Tween<Transform>, &mut Transform, Option<&TweenPath>)>,
+) {
     let dt = time.delta_seconds();
 
-    for (animation, mut tf) in &mut targets {
-        tf.translation = tf.translation.lerp(animation.target.translation, 3.0 * dt);
-        tf.rotation = tf.rotation.lerp(animation.target.rotation, 3.0 * dt);
+    for (mut animation, mut tf, path) in &mut targets {
+        if !animation.finished() {
+            animation.elapsed += dt;
+        }
+
+        let current = animation.current(path);
+
+        tf.translation = current.translation;
+        tf.rotation = current.rotation;
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn tween_projections(
+    time: Res<Time>,
+    mut targets: Query<(&mut Tween<Projection>, &mut Projection)>,
+) {
+    let dt = time.delta_seconds();
+
+    for (mut animation, mut projection) in &mut targets {
+        if !animation.finished() {
+            animation.elapsed += dt;
+        }
+
+        let t = animation.easing.apply(animation.progress());
+        let mut changed = false;
+
+        // Only the FOV or scale is animated, the aspect ratio and area are kept up to date by Bevy
+        match (
+            &animation.start,
+            &animation.target,
+            projection.bypass_change_detection(),
+        ) {
+            (
+                Projection::Perspective(start),
+                Projection::Perspective(target),
+                Projection::Perspective(current),
+            ) => {
+                let fov = start.fov + (target.fov - start.fov) * t;
+
+                if (current.fov - fov).abs() > f32::EPSILON {
+                    current.fov = fov;
+                    changed = true;
+                }
+            }
+            (
+                Projection::Orthographic(start),
+                Projection::Orthographic(target),
+                Projection::Orthographic(current),
+            ) => {
+                let scale = start.scale + (target.scale - start.scale) * t;
+
+                if (current.scale - scale).abs() > f32::EPSILON {
+                    current.scale = scale;
+                    changed = true;
+                }
+            }
+            (_, target, current) => {
+                // Perspective and orthographic projections can't be blended, so switch right away
+                if std::mem::discriminant(target) != std::mem::discriminant(current) {
+                    *current = target.clone();
+                    changed = true;
+                }
+            }
+        }
+
+        if changed {
+            projection.set_changed();
+        }
     }
 }
 
 #[derive(Resource, Debug, Default)]
 pub struct AnimationRegistry {
     map: HashMap<String, Handle<AnimationClip>>,
+    registered: Vec<AssetId<AnimationClip>>,
 }
 
 impl AnimationRegistry {
     fn insert(&mut self, name: &str, handle: Handle<AnimationClip>) {
+        self.registered.push(handle.id());
         self.map.insert(name.to_owned(), handle);
     }
 
@@ -165,6 +424,11 @@ impl<'w, 's> AnimationServer<'w, 's> {
         self
     }
 
+    /// Returns the ids of the animations loaded since the last call.
+    pub(crate) fn take_registered(&mut self) -> Vec<AssetId<AnimationClip>> {
+        std::mem::take(&mut self.registry.registered)
+    }
+
     /// Returns a handle to a loaded `AnimationClip` given a name.
     pub fn get(&self, name: &str) -> Option<Handle<AnimationClip>> {
         self.registry.get(name)
@@ -183,3 +447,62 @@ impl<'w, 's> AnimationServer<'w, 's> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easing_keeps_endpoints() {
+        for easing in [Easing::Linear, Easing::EaseInOut, Easing::Cubic] {
+            assert!(easing.apply(0.0).abs() < f32::EPSILON);
+            assert!((easing.apply(1.0) - 1.0).abs() < f32::EPSILON);
+            assert!((easing.apply(0.5) - 0.5).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn easing_clamps_progress() {
+        assert!(Easing::Cubic.apply(-1.0).abs() < f32::EPSILON);
+        assert!((Easing::EaseInOut.apply(2.0) - 1.0).abs() < f32::EPSILON);
+        assert!((Easing::Custom(|t| t * t).apply(2.0) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn path_passes_through_endpoints_and_points() {
+        let path = TweenPath::new(vec![Vec3::new(1.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 1.0)]);
+        let (start, end) = (Vec3::ZERO, Vec3::new(2.0, 0.0, 1.0));
+
+        assert!(path.sample(start, end, 0.0).distance(start) < 1e-5);
+        assert!(path.sample(start, end, 1.0).distance(end) < 1e-5);
+
+        // The segments all have a length of 1, so each point is reached at a third of the way
+        assert!(path.sample(start, end, 1.0 / 3.0).distance(path.points[0]) < 1e-4);
+        assert!(path.sample(start, end, 2.0 / 3.0).distance(path.points[1]) < 1e-4);
+    }
+
+    #[test]
+    fn empty_path_stays_on_the_line() {
+        let path = TweenPath::default();
+        let end = Vec3::new(4.0, 0.0, 0.0);
+
+        for t in [0.1, 0.25, 0.5, 0.75, 0.9] {
+            let point = path.sample(Vec3::ZERO, end, t);
+
+            assert!(point.y.abs() < 1e-5 && point.z.abs() < 1e-5);
+            assert!(point.x > 0.0 && point.x < end.x);
+        }
+
+        assert!(path.sample(end, end, 0.5).distance(end) < 1e-5);
+    }
+
+    #[test]
+    fn easing_from_name() {
+        assert!(matches!(
+            Easing::from_name("Ease-In-Out"),
+            Some(Easing::EaseInOut)
+        ));
+        assert!(matches!(Easing::from_name("linear"), Some(Easing::Linear)));
+        assert!(Easing::from_name("bounce").is_none());
+    }
+}
@@ -31,10 +31,12 @@ fn cleanup_audio(mut commands: Commands, sinks: Query<(Entity, &AudioSink), With
 #[derive(Resource, Debug, Default)]
 pub struct AudioRegistry {
     map: HashMap<String, Handle<AudioSource>>,
+    registered: Vec<AssetId<AudioSource>>,
 }
 
 impl AudioRegistry {
     fn insert(&mut self, name: &str, handle: Handle<AudioSource>) {
+        self.registered.push(handle.id());
         self.map.insert(name.to_owned(), handle);
     }
 
@@ -65,6 +67,11 @@ impl<'w, 's> AudioServer<'w, 's> {
         self
     }
 
+    /// Returns the ids of the audio clips loaded since the last call.
+    pub(crate) fn take_registered(&mut self) -> Vec<AssetId<AudioSource>> {
+        std::mem::take(&mut self.registry.registered)
+    }
+
     /// Play an `AudioSource` by path.
     pub fn play(&mut self, name: &str) {
         if let Some(source) = self.registry.get(name) {
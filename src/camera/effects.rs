@@ -0,0 +1,312 @@
+#[cfg(not(feature = "ui"))]
+use bevy::pbr::NotShadowCaster;
+use bevy::prelude::*;
+
+use crate::{
+    animation::{
+        tween_transforms,
+        Tween,
+    },
+    camera::CameraSettings,
+};
+
+pub struct CameraEffectsPlugin;
+
+impl Plugin for CameraEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app ////
+            .register_type::<CameraShake>()
+            .register_type::<ScreenFade>()
+            .register_type::<Zoom>()
+            ////
+            .init_resource::<ScreenFade>()
+            ////
+            .add_systems(
+                Update,
+                (
+                    shake_camera.after(tween_transforms),
+                    (fade_screen, draw_fade).chain(),
+                    zoom_camera,
+                ),
+            );
+    }
+}
+
+/// Insert this resource to shake the Camera.
+///
+/// The shake is applied on top of the Camera's position, and fades out over its duration.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource)]
+pub struct CameraShake {
+    /// How far the Camera moves away from its position, in world units.
+    pub intensity: f32,
+
+    /// How long the shake lasts, in seconds.
+    pub duration: f32,
+
+    elapsed: f32,
+}
+
+impl CameraShake {
+    /// Returns a new `CameraShake` with the given intensity, in world units, and duration, in seconds.
+    pub fn new(intensity: f32, duration: f32) -> Self {
+        Self {
+            intensity,
+            duration,
+            elapsed: 0.0,
+        }
+    }
+}
+
+/// Insert this resource to zoom the Camera to another field of view, in radians
+/// (or another scale, for orthographic cameras), over the given duration in seconds.
+///
+/// The zoom lasts until the Camera moves to another spot.
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource)]
+pub struct Zoom {
+    /// The field of view (or scale) to zoom to.
+    pub fov: f32,
+
+    /// How long zooming takes, in seconds.
+    pub duration: f32,
+}
+
+/// Resource that controls a color overlay that covers the whole window, to fade out and back in.
+///
+/// With the `ui` feature, the overlay is a UI node that also covers the UI.
+#[derive(Resource, Clone, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource)]
+pub struct ScreenFade {
+    color: Color,
+    start: f32,
+    target: f32,
+    elapsed: f32,
+    duration: f32,
+}
+
+impl Default for ScreenFade {
+    fn default() -> Self {
+        Self {
+            color: Color::BLACK,
+            start: 0.0,
+            target: 0.0,
+            elapsed: 0.0,
+            duration: 0.0,
+        }
+    }
+}
+
+impl ScreenFade {
+    /// Fade the screen out to the given color, over the given duration in seconds.
+    pub fn fade_out(&mut self, color: Color, duration: f32) {
+        self.start = self.opacity();
+        self.color = color;
+        self.target = 1.0;
+        self.elapsed = 0.0;
+        self.duration = duration;
+    }
+
+    /// Fade the screen back in, over the given duration in seconds.
+    pub fn fade_in(&mut self, duration: f32) {
+        self.start = self.opacity();
+        self.target = 0.0;
+        self.elapsed = 0.0;
+        self.duration = duration;
+    }
+
+    /// Returns the color of the overlay.
+    pub fn color(&self) -> Color {
+        self.color
+    }
+
+    /// Returns how much the overlay covers the screen right now, from 0 to 1.
+    pub fn opacity(&self) -> f32 {
+        let t = if self.duration > 0.0 {
+            (self.elapsed / self.duration).min(1.0)
+        } else {
+            1.0
+        };
+
+        self.start + (self.target - self.start) * t
+    }
+
+    /// Returns true once the fade has finished.
+    pub fn finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Returns true if the screen is completely covered by the overlay.
+    pub fn is_opaque(&self) -> bool {
+        self.opacity() >= 1.0
+    }
+}
+
+/// Marker component for the overlay spawned by [`ScreenFade`].
+#[derive(Component, Debug)]
+struct FadeOverlay;
+
+/// Distance between the Camera and the fade overlay, without the `ui` feature.
+#[cfg(not(feature = "ui"))]
+const OVERLAY_DISTANCE: f32 = 0.2;
+
+#[allow(clippy::needless_pass_by_value)]
+pub(crate) fn shake_camera(
+    mut commands: Commands,
+    time: Res<Time>,
+    shake: Option<ResMut<CameraShake>>,
+    mut cameras: Query<&mut Transform, (With<Camera>, With<Tween<Transform>>)>,
+) {
+    let Some(mut shake) = shake else {
+        return;
+    };
+
+    shake.elapsed += time.delta_seconds();
+
+    if shake.elapsed >= shake.duration {
+        commands.remove_resource::<CameraShake>();
+        return;
+    }
+
+    // The Tween sets the position of the spot every frame, the shake is applied on top of it
+    let t = shake.elapsed;
+    let strength = shake.intensity * (1.0 - t / shake.duration);
+
+    let offset = Vec3::new(
+        (t * 37.0).sin() + (t * 83.0).sin() * 0.5,
+        (t * 41.0 + 1.3).sin() + (t * 97.0).sin() * 0.5,
+        0.0,
+    ) * strength;
+
+    for mut tf in &mut cameras {
+        let local = tf.rotation * offset;
+        tf.translation += local;
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn fade_screen(time: Res<Time>, mut fade: ResMut<ScreenFade>) {
+    if !fade.finished() {
+        fade.elapsed += time.delta_seconds();
+    }
+}
+
+/// Returns the color of the overlay right now, or None once it is fully transparent.
+fn overlay_color(fade: &ScreenFade) -> Option<Color> {
+    let opacity = fade.opacity();
+
+    (opacity > 0.0).then(|| fade.color.with_a(fade.color.a() * opacity))
+}
+
+/// Draws the overlay as a UI node, so it covers the UI as well.
+#[cfg(feature = "ui")]
+#[allow(clippy::needless_pass_by_value)]
+fn draw_fade(
+    mut commands: Commands,
+    fade: Res<ScreenFade>,
+    mut overlays: Query<(Entity, &mut BackgroundColor), With<FadeOverlay>>,
+) {
+    let color = overlay_color(&fade);
+
+    if let Ok((entity, mut background)) = overlays.get_single_mut() {
+        match color {
+            None => commands.entity(entity).despawn_recursive(),
+            Some(color) if background.0 != color => background.0 = color,
+            Some(_) => (),
+        }
+    } else if let Some(color) = color {
+        commands.spawn((Name::new("Screen Fade"), FadeOverlay, NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            background_color: color.into(),
+            // Above everything but the loading screen
+            z_index: ZIndex::Global(i32::MAX - 1),
+            ..default()
+        }));
+    }
+}
+
+/// Draws the overlay as a quad in front of the Camera, since there is no UI to cover without the `ui` feature.
+#[cfg(not(feature = "ui"))]
+#[allow(clippy::needless_pass_by_value)]
+fn draw_fade(
+    mut commands: Commands,
+    fade: Res<ScreenFade>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    cameras: Query<Entity, (With<Camera>, With<Tween<Transform>>)>,
+    overlays: Query<(Entity, &Handle<StandardMaterial>), With<FadeOverlay>>,
+) {
+    let color = overlay_color(&fade);
+
+    if let Ok((entity, material)) = overlays.get_single() {
+        match color {
+            None => commands.entity(entity).despawn_recursive(),
+            Some(color) => {
+                if let Some(material) = materials
+                    .get_mut(material)
+                    .filter(|material| material.base_color != color)
+                {
+                    material.base_color = color;
+                }
+            }
+        }
+    } else if let Some(color) = color {
+        let Ok(camera) = cameras.get_single() else {
+            return;
+        };
+
+        let overlay = commands
+            .spawn((
+                Name::new("Screen Fade"),
+                FadeOverlay,
+                NotShadowCaster,
+                PbrBundle {
+                    mesh: meshes.add(shape::Quad::new(Vec2::splat(1000.0)).into()),
+                    material: materials.add(StandardMaterial {
+                        base_color: color,
+                        alpha_mode: AlphaMode::Blend,
+                        unlit: true,
+                        ..default()
+                    }),
+                    transform: Transform::from_xyz(0.0, 0.0, -OVERLAY_DISTANCE),
+                    ..default()
+                },
+            ))
+            .id();
+
+        commands.entity(camera).add_child(overlay);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn zoom_camera(
+    mut commands: Commands,
+    settings: Res<CameraSettings>,
+    zoom: Option<Res<Zoom>>,
+    mut cameras: Query<(&Projection, &mut Tween<Projection>), With<Camera>>,
+) {
+    let Some(zoom) = zoom else {
+        return;
+    };
+
+    commands.remove_resource::<Zoom>();
+
+    if let Ok((projection, mut animation)) = cameras.get_single_mut() {
+        let mut target = projection.clone();
+
+        match &mut target {
+            Projection::Perspective(perspective) => perspective.fov = zoom.fov,
+            Projection::Orthographic(orthographic) => orthographic.scale = zoom.fov,
+        }
+
+        animation.animate(projection.clone(), target, zoom.duration, settings.easing);
+    }
+}
@@ -0,0 +1,133 @@
+use bevy::prelude::*;
+
+pub struct HistoryPlugin;
+
+impl Plugin for HistoryPlugin {
+    fn build(&self, app: &mut App) {
+        app ////
+            .register_type::<SpotHistory>()
+            .register_type::<GoBack>()
+            ////
+            .init_resource::<SpotHistory>();
+    }
+}
+
+/// Insert this resource to go back to the previous `CameraSpot` in the [`SpotHistory`].
+///
+/// If there is no previous spot, the Camera moves to `MAIN_CAMERA` instead.
+/// It is ignored if a `NextSpot` is inserted in the same frame.
+#[derive(Resource, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource)]
+pub struct GoBack;
+
+/// Resource that stores the `CameraSpot`s the player has visited in the current scene, most recent last.
+///
+/// Going back (clicking the inventory strip, or `Action::Back`) returns to the most recent spot,
+/// unless the current spot has a `BackToState`, or it (or the interactive it looks at) has a `BackToSpot`.
+/// Moving to a spot that is already in the history goes back to it, so the history never loops.
+///
+/// The history is cleared when entering a scene.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct SpotHistory {
+    scene: Option<String>,
+    spots: Vec<String>,
+}
+
+impl SpotHistory {
+    /// Returns the path of the scene the history belongs to, if any.
+    pub fn scene(&self) -> Option<&str> {
+        self.scene.as_deref()
+    }
+
+    /// Returns the visited spots, most recent last.
+    pub fn spots(&self) -> &[String] {
+        &self.spots
+    }
+
+    /// Returns the name of the spot that going back returns to, if any.
+    pub fn previous(&self) -> Option<&str> {
+        self.spots.last().map(String::as_str)
+    }
+
+    /// Returns true if there is no spot to go back to.
+    pub fn is_empty(&self) -> bool {
+        self.spots.is_empty()
+    }
+
+    /// Remove every spot from the history.
+    pub fn clear(&mut self) {
+        self.spots.clear();
+    }
+
+    /// Start a new history for the given scene.
+    pub(crate) fn enter(&mut self, scene: &str) {
+        self.scene = Some(scene.to_owned());
+        self.clear();
+    }
+
+    /// Record a move from one spot to another.
+    pub(crate) fn visit(&mut self, from: &str, to: &str) {
+        if let Some(index) = self.spots.iter().position(|spot| spot == to) {
+            self.spots.truncate(index);
+        } else if from != to {
+            self.spots.push(from.to_owned());
+        }
+    }
+
+    pub(crate) fn pop(&mut self) -> Option<String> {
+        self.spots.pop()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visit_records_previous_spots() {
+        let mut history = SpotHistory::default();
+        history.enter("scene.glb#Scene0");
+
+        history.visit("Main", "Desk");
+        history.visit("Desk", "Drawer");
+
+        assert_eq!(history.spots(), ["Main", "Desk"]);
+        assert_eq!(history.previous(), Some("Desk"));
+        assert_eq!(history.pop().as_deref(), Some("Desk"));
+        assert_eq!(history.previous(), Some("Main"));
+    }
+
+    #[test]
+    fn visiting_a_previous_spot_truncates() {
+        let mut history = SpotHistory::default();
+
+        history.visit("Main", "Desk");
+        history.visit("Desk", "Drawer");
+        history.visit("Drawer", "Shelf");
+        history.visit("Shelf", "Desk");
+
+        assert_eq!(history.spots(), ["Main"]);
+    }
+
+    #[test]
+    fn staying_at_a_spot_is_not_recorded() {
+        let mut history = SpotHistory::default();
+
+        history.visit("Main", "Main");
+        assert!(history.is_empty());
+    }
+
+    #[test]
+    fn entering_a_scene_clears() {
+        let mut history = SpotHistory::default();
+
+        history.visit("Main", "Desk");
+        history.enter("other.glb#Scene0");
+
+        assert!(history.is_empty());
+        assert_eq!(history.scene(), Some("other.glb#Scene0"));
+    }
+}
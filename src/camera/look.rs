@@ -0,0 +1,219 @@
+use bevy::{
+    input::mouse::MouseMotion,
+    prelude::*,
+};
+use serde_json::{
+    Map,
+    Value,
+};
+
+use crate::{
+    animation::{
+        tween_transforms,
+        Tween,
+    },
+    camera::{
+        CameraMoveStarted,
+        CameraMoving,
+        CurrentSpot,
+    },
+    examine::Examining,
+    Cursor,
+    INVENTORY_HEIGHT,
+};
+
+pub struct LookAroundPlugin;
+
+impl Plugin for LookAroundPlugin {
+    fn build(&self, app: &mut App) {
+        app ////
+            .register_type::<LookAround>()
+            .register_type::<LookSettings>()
+            .register_type::<LookOffset>()
+            ////
+            .init_resource::<LookSettings>()
+            .init_resource::<LookOffset>()
+            ////
+            .add_systems(Update, look_around.after(tween_transforms));
+    }
+}
+
+/// A component for a `CameraSpot` that lets the player look around while at that spot.
+///
+/// The player can pan the view by moving the cursor to the edges of the window, or by dragging with the right mouse button.
+/// The view springs back when moving to another spot.
+///
+/// Can also be set from the glTF extras (custom properties) of the camera,
+/// with `look_yaw` and `look_pitch` numbers, in degrees.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct LookAround {
+    /// How far the view can turn to either side, in radians.
+    pub yaw: f32,
+
+    /// How far the view can turn up or down, in radians.
+    pub pitch: f32,
+}
+
+impl LookAround {
+    /// Returns a new `LookAround` with the given limits, in radians.
+    pub fn new(yaw: f32, pitch: f32) -> Self {
+        Self { yaw, pitch }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn from_extras(extras: &Map<String, Value>) -> Option<Self> {
+        let angle = |key| {
+            extras
+                .get(key)
+                .and_then(Value::as_f64)
+                .map(|degrees| (degrees as f32).to_radians())
+        };
+
+        let (yaw, pitch) = (angle("look_yaw"), angle("look_pitch"));
+
+        (yaw.is_some() || pitch.is_some()).then(|| Self {
+            yaw: yaw.unwrap_or_default(),
+            pitch: pitch.unwrap_or_default(),
+        })
+    }
+}
+
+/// A resource that configures how the player looks around a `CameraSpot` with [`LookAround`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct LookSettings {
+    /// Width of the area along the edges of the window that pans the view, in logical pixels.
+    ///
+    /// The bottom edge is the top of the inventory strip.
+    pub edge: f32,
+
+    /// How fast the view pans while the cursor is at an edge, in radians per second.
+    pub edge_speed: f32,
+
+    /// Rotation applied for each logical pixel of mouse movement while right-dragging, in radians.
+    pub drag_speed: f32,
+
+    /// How quickly the view follows the player's input and springs back, higher is faster.
+    pub stiffness: f32,
+}
+
+impl Default for LookSettings {
+    fn default() -> Self {
+        Self {
+            edge: 32.0,
+            edge_speed: 1.0,
+            drag_speed: 0.005,
+            stiffness: 8.0,
+        }
+    }
+}
+
+/// Resource that stores how far the view is turned away from the current `CameraSpot`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct LookOffset {
+    yaw: f32,
+    pitch: f32,
+    target: Vec2,
+
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    edges_blocked: bool,
+}
+
+impl LookOffset {
+    /// Returns how far the view is turned to the left, in radians.
+    pub fn yaw(&self) -> f32 {
+        self.yaw
+    }
+
+    /// Returns how far the view is turned up, in radians.
+    pub fn pitch(&self) -> f32 {
+        self.pitch
+    }
+
+    /// Stop the edges of the window from panning the view this frame, like while the cursor is over a button.
+    #[cfg_attr(not(feature = "ui"), allow(dead_code))]
+    pub(crate) fn block_edges(&mut self) {
+        self.edges_blocked = true;
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn look_around(
+    time: Res<Time>,
+    input: Res<Input<MouseButton>>,
+    cursor: Res<Cursor>,
+    settings: Res<LookSettings>,
+    at_spot: Res<CurrentSpot>,
+    moving: Res<CameraMoving>,
+    examining: Option<Res<Examining>>,
+    mut offset: ResMut<LookOffset>,
+    mut started: EventReader<CameraMoveStarted>,
+    mut motion: EventReader<MouseMotion>,
+    windows: Query<&Window>,
+    limits: Query<&LookAround>,
+    mut cameras: Query<&mut Transform, (With<Camera>, With<Tween<Transform>>)>,
+) {
+    let delta = motion.read().map(|event| event.delta).sum::<Vec2>();
+    let dt = time.delta_seconds();
+    let edges = !std::mem::take(&mut offset.edges_blocked);
+
+    if started.read().count() > 0 {
+        offset.target = Vec2::ZERO;
+    }
+
+    let limit = at_spot
+        .entity()
+        .filter(|_| !moving.is_moving() && examining.is_none())
+        .and_then(|spot| limits.get(spot).ok())
+        .copied()
+        .unwrap_or_default();
+
+    if let Ok(window) = windows.get_single() {
+        let position = cursor.position();
+        let bottom = window.height() - INVENTORY_HEIGHT;
+
+        if position.y < bottom {
+            let mut pan = Vec2::ZERO;
+
+            if input.pressed(MouseButton::Right) {
+                pan = -delta * settings.drag_speed;
+            } else if edges && window.cursor_position().is_some() {
+                if position.x < settings.edge {
+                    pan.x += settings.edge_speed * dt;
+                } else if position.x > window.width() - settings.edge {
+                    pan.x -= settings.edge_speed * dt;
+                }
+
+                if position.y < settings.edge {
+                    pan.y += settings.edge_speed * dt;
+                } else if position.y > bottom - settings.edge {
+                    pan.y -= settings.edge_speed * dt;
+                }
+            }
+
+            offset.target += pan;
+        }
+    }
+
+    let max = Vec2::new(limit.yaw.abs(), limit.pitch.abs());
+    offset.target = offset.target.clamp(-max, max);
+
+    let blend = 1.0 - (-settings.stiffness * dt).exp();
+    let current = Vec2::new(offset.yaw, offset.pitch).lerp(offset.target, blend);
+
+    offset.yaw = current.x;
+    offset.pitch = current.y;
+
+    // The Tween sets the rotation of the spot every frame, the offset is applied on top of it
+    for mut tf in &mut cameras {
+        tf.rotation =
+            Quat::from_rotation_y(offset.yaw) * tf.rotation * Quat::from_rotation_x(offset.pitch);
+    }
+}
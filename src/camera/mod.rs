@@ -1,6 +1,13 @@
 mod back;
+pub(crate) mod effects;
+mod history;
 mod ignores;
+pub(crate) mod look;
+mod motion;
+mod moving;
+mod neighbors;
 mod next;
+mod path;
 mod plugin;
 mod spot;
 
@@ -8,15 +15,50 @@ pub use back::{
     BackToSpot,
     BackToState,
 };
+pub use effects::{
+    CameraShake,
+    ScreenFade,
+    Zoom,
+};
+pub use history::{
+    GoBack,
+    SpotHistory,
+};
 pub use ignores::Ignores;
+pub use look::{
+    LookAround,
+    LookOffset,
+    LookSettings,
+};
+pub use motion::{
+    CameraMotion,
+    CameraSettings,
+};
+pub use moving::{
+    CameraArrived,
+    CameraMoveStarted,
+    CameraMoving,
+};
+pub use neighbors::{
+    Navigate,
+    NavigationSettings,
+    Neighbors,
+    SpotDirection,
+};
 pub use next::{
+    EntrySpot,
     NextSpot,
     SkipAnimation,
 };
+pub use path::{
+    CameraPath,
+    CameraPaths,
+};
 pub use plugin::CameraPlugin;
 pub use spot::{
     CameraSpot,
     CameraSpots,
     CurrentSpot,
+    FocusSpot,
     IsCameraSpot,
 };
@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+use serde_json::{
+    Map,
+    Value,
+};
+
+use crate::animation::Easing;
+
+pub struct CameraMotionPlugin;
+
+impl Plugin for CameraMotionPlugin {
+    fn build(&self, app: &mut App) {
+        app ////
+            .register_type::<CameraSettings>()
+            .register_type::<CameraMotion>()
+            .register_type::<Option<f32>>()
+            .register_type::<Option<Easing>>()
+            ////
+            .init_resource::<CameraSettings>();
+    }
+}
+
+/// A resource that configures how the Camera moves between `CameraSpot`s.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct CameraSettings {
+    /// How long it takes to move to another spot, in seconds.
+    pub duration: f32,
+
+    /// The easing function used when moving to another spot.
+    pub easing: Easing,
+
+    /// Whether interactions are ignored until the Camera arrives at the spot it is moving to.
+    pub block_interactions: bool,
+}
+
+impl Default for CameraSettings {
+    fn default() -> Self {
+        Self {
+            duration: 1.0,
+            easing: Easing::EaseInOut,
+            block_interactions: false,
+        }
+    }
+}
+
+/// A component for a `CameraSpot` that overrides the [`CameraSettings`] when moving to that spot.
+///
+/// Can also be set from the glTF extras (custom properties) of the camera,
+/// with a `duration` number and an `easing` string (`"linear"`, `"ease_in_out"` or `"cubic"`).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct CameraMotion {
+    /// How long it takes to move to the spot, in seconds.
+    pub duration: Option<f32>,
+
+    /// The easing function used when moving to the spot.
+    pub easing: Option<Easing>,
+}
+
+impl CameraMotion {
+    /// Returns a new `CameraMotion` that does not override anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the duration of the motion, in seconds.
+    #[must_use]
+    pub fn duration(mut self, duration: f32) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Set the easing function of the motion.
+    #[must_use]
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = Some(easing);
+        self
+    }
+
+    /// Returns the duration and easing to use, given the defaults from [`CameraSettings`].
+    pub fn resolve(motion: Option<&Self>, settings: &CameraSettings) -> (f32, Easing) {
+        (
+            motion.and_then(|m| m.duration).unwrap_or(settings.duration),
+            motion.and_then(|m| m.easing).unwrap_or(settings.easing),
+        )
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn from_extras(extras: &Map<String, Value>) -> Option<Self> {
+        let duration = extras
+            .get("duration")
+            .and_then(Value::as_f64)
+            .map(|duration| duration as f32);
+
+        let easing = extras
+            .get("easing")
+            .and_then(Value::as_str)
+            .and_then(|name| {
+                let easing = Easing::from_name(name);
+
+                if easing.is_none() {
+                    warn!("Unknown easing {:?}", name);
+                }
+
+                easing
+            });
+
+        (duration.is_some() || easing.is_some()).then_some(Self { duration, easing })
+    }
+}
@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+
+use crate::animation::Tween;
+
+pub struct CameraMovingPlugin;
+
+impl Plugin for CameraMovingPlugin {
+    fn build(&self, app: &mut App) {
+        app ////
+            .register_type::<CameraMoving>()
+            ////
+            .init_resource::<CameraMoving>()
+            ////
+            .add_event::<CameraMoveStarted>()
+            .add_event::<CameraArrived>()
+            ////
+            .add_systems(PostUpdate, detect_arrival);
+    }
+}
+
+/// An event that fires whenever the Camera starts moving to a `CameraSpot`.
+#[derive(Event, Clone, Debug)]
+pub struct CameraMoveStarted {
+    /// The name of the spot the Camera was at, if any.
+    pub from: Option<String>,
+
+    /// The name of the spot the Camera is moving to.
+    pub to: String,
+}
+
+/// An event that fires whenever the Camera has finished moving to a `CameraSpot`.
+#[derive(Event, Clone, Debug)]
+pub struct CameraArrived {
+    /// The name of the spot the Camera arrived at.
+    pub spot: String,
+}
+
+/// Resource that tracks whether the Camera is moving to a `CameraSpot`.
+///
+/// Interactions can be blocked while the Camera is moving with [`CameraSettings::block_interactions`](crate::CameraSettings).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct CameraMoving {
+    target: Option<String>,
+}
+
+impl CameraMoving {
+    /// Returns true if the Camera is moving.
+    pub fn is_moving(&self) -> bool {
+        self.target.is_some()
+    }
+
+    /// Returns the name of the spot the Camera is moving to, if it is moving.
+    pub fn target(&self) -> Option<&str> {
+        self.target.as_deref()
+    }
+
+    pub(crate) fn start(&mut self, spot: &str) {
+        self.target = Some(spot.to_owned());
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn detect_arrival(
+    mut moving: ResMut<CameraMoving>,
+    mut arrived: EventWriter<CameraArrived>,
+    cameras: Query<&Tween<Transform>, With<Camera>>,
+) {
+    if !moving.is_moving() {
+        return;
+    }
+
+    if cameras
+        .get_single()
+        .map_or(true, |animation| animation.finished())
+    {
+        if let Some(spot) = moving.target.take() {
+            arrived.send(CameraArrived { spot });
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use bevy::prelude::*;
+use serde_json::{
+    Map,
+    Value,
+};
+
+use crate::{
+    camera::{
+        CameraMoving,
+        CameraSettings,
+        CurrentSpot,
+        NextSpot,
+    },
+    examine::Examining,
+    transition::Transitioning,
+};
+
+pub struct NeighborsPlugin;
+
+impl Plugin for NeighborsPlugin {
+    fn build(&self, app: &mut App) {
+        app ////
+            .register_type::<Neighbors>()
+            .register_type::<SpotDirection>()
+            .register_type::<NavigationSettings>()
+            ////
+            .init_resource::<NavigationSettings>()
+            ////
+            .add_event::<Navigate>()
+            ////
+            .add_systems(PreUpdate, navigation_input)
+            .add_systems(Update, navigate);
+    }
+}
+
+/// A direction the player can turn or walk in, from one `CameraSpot` to the next.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+pub enum SpotDirection {
+    /// Turn to the left.
+    Left,
+
+    /// Turn to the right.
+    Right,
+
+    /// Walk forward.
+    Forward,
+
+    /// Turn around or walk back.
+    Back,
+}
+
+impl SpotDirection {
+    /// Every direction, in order.
+    pub const ALL: [Self; 4] = [Self::Left, Self::Right, Self::Forward, Self::Back];
+
+    fn key(self) -> &'static str {
+        match self {
+            Self::Left => "left",
+            Self::Right => "right",
+            Self::Forward => "forward",
+            Self::Back => "back",
+        }
+    }
+}
+
+/// A component for a `CameraSpot` that declares which spots are next to it, so the player can turn around a room
+/// with the arrow keys, a gamepad's directional pad, or the on-screen arrows of `NavigationUiPlugin`.
+///
+/// Can also be set from the glTF extras (custom properties) of the camera,
+/// with `left`, `right`, `forward` and `back` strings naming the neighboring cameras.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct Neighbors {
+    /// The spot to the left.
+    pub left: Option<String>,
+
+    /// The spot to the right.
+    pub right: Option<String>,
+
+    /// The spot ahead.
+    pub forward: Option<String>,
+
+    /// The spot behind.
+    pub back: Option<String>,
+}
+
+impl Neighbors {
+    /// Returns a new `Neighbors` with no neighboring spots.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the spot to the left.
+    #[must_use]
+    pub fn left(mut self, name: &str) -> Self {
+        self.left = Some(name.to_owned());
+        self
+    }
+
+    /// Set the spot to the right.
+    #[must_use]
+    pub fn right(mut self, name: &str) -> Self {
+        self.right = Some(name.to_owned());
+        self
+    }
+
+    /// Set the spot ahead.
+    #[must_use]
+    pub fn forward(mut self, name: &str) -> Self {
+        self.forward = Some(name.to_owned());
+        self
+    }
+
+    /// Set the spot behind.
+    #[must_use]
+    pub fn back(mut self, name: &str) -> Self {
+        self.back = Some(name.to_owned());
+        self
+    }
+
+    /// Returns the name of the spot in the given direction, if any.
+    pub fn get(&self, direction: SpotDirection) -> Option<&str> {
+        match direction {
+            SpotDirection::Left => self.left.as_deref(),
+            SpotDirection::Right => self.right.as_deref(),
+            SpotDirection::Forward => self.forward.as_deref(),
+            SpotDirection::Back => self.back.as_deref(),
+        }
+    }
+
+    pub(crate) fn from_extras(extras: &Map<String, Value>) -> Option<Self> {
+        let mut neighbors = Self::new();
+        let mut found = false;
+
+        for direction in SpotDirection::ALL {
+            if let Some(name) = extras.get(direction.key()).and_then(Value::as_str) {
+                let name = Some(name.to_owned());
+
+                match direction {
+                    SpotDirection::Left => neighbors.left = name,
+                    SpotDirection::Right => neighbors.right = name,
+                    SpotDirection::Forward => neighbors.forward = name,
+                    SpotDirection::Back => neighbors.back = name,
+                }
+
+                found = true;
+            }
+        }
+
+        found.then_some(neighbors)
+    }
+}
+
+/// Send this event to move to the neighbor of the current `CameraSpot` in the given direction, see [`Neighbors`].
+#[derive(Event, Clone, Copy, Debug)]
+pub struct Navigate(pub SpotDirection);
+
+/// A resource that configures which inputs move between [`Neighbors`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct NavigationSettings {
+    /// Whether the arrow keys move between spots.
+    pub keyboard: bool,
+
+    /// Whether the directional pad of a gamepad moves between spots.
+    pub gamepad: bool,
+}
+
+impl Default for NavigationSettings {
+    fn default() -> Self {
+        Self {
+            keyboard: true,
+            gamepad: true,
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn navigation_input(
+    settings: Res<NavigationSettings>,
+    keys: Res<Input<KeyCode>>,
+    buttons: Res<Input<GamepadButton>>,
+    gamepads: Res<Gamepads>,
+    mut navigate: EventWriter<Navigate>,
+) {
+    for direction in SpotDirection::ALL {
+        let (key, button) = match direction {
+            SpotDirection::Left => (KeyCode::Left, GamepadButtonType::DPadLeft),
+            SpotDirection::Right => (KeyCode::Right, GamepadButtonType::DPadRight),
+            SpotDirection::Forward => (KeyCode::Up, GamepadButtonType::DPadUp),
+            SpotDirection::Back => (KeyCode::Down, GamepadButtonType::DPadDown),
+        };
+
+        let pressed = (settings.keyboard && keys.just_pressed(key))
+            || (settings.gamepad
+                && gamepads
+                    .iter()
+                    .any(|gamepad| buttons.just_pressed(GamepadButton::new(gamepad, button))));
+
+        if pressed {
+            navigate.send(Navigate(direction));
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::too_many_arguments)]
+fn navigate(
+    mut commands: Commands,
+    settings: Res<CameraSettings>,
+    moving: Res<CameraMoving>,
+    at_spot: Res<CurrentSpot>,
+    examining: Option<Res<Examining>>,
+    transitioning: Option<Res<Transitioning>>,
+    mut events: EventReader<Navigate>,
+    neighbors: Query<&Neighbors>,
+) {
+    let Some(Navigate(direction)) = events.read().last().copied() else {
+        return;
+    };
+
+    let Some(spot) = at_spot.entity() else {
+        return;
+    };
+
+    if examining.is_some()
+        || transitioning.is_some()
+        || (settings.block_interactions && moving.is_moving())
+    {
+        return;
+    }
+
+    let next = neighbors
+        .get(spot)
+        .ok()
+        .and_then(|neighbors| neighbors.get(direction));
+
+    if let Some(next) = next {
+        commands.insert_resource(NextSpot(next.to_owned()));
+    }
+}
@@ -4,10 +4,23 @@ use bevy::{
 };
 
 use crate::{
-    animation::Tween,
-    camera::spot::CurrentSpot,
+    animation::{
+        Tween,
+        TweenPath,
+    },
+    camera::{
+        spot::CurrentSpot,
+        CameraMotion,
+        CameraMoveStarted,
+        CameraMoving,
+        CameraPaths,
+        CameraSettings,
+        GoBack,
+        SpotHistory,
+    },
     scene::SceneManager,
     CameraSpots,
+    MAIN_CAMERA,
 };
 
 pub struct NextPlugin;
@@ -17,6 +30,7 @@ impl Plugin for NextPlugin {
         app ////
             .register_type::<SkipAnimation>()
             .register_type::<NextSpot>()
+            .register_type::<EntrySpot>()
             ////
             .add_systems(Last, handle_next_spot);
     }
@@ -34,6 +48,14 @@ pub struct SkipAnimation;
 #[reflect(Resource)]
 pub struct NextSpot(pub String);
 
+/// Insert this resource when changing the state to determine what `CameraSpot` the next scene starts at.
+///
+/// Without it, scenes start at `MAIN_CAMERA`.
+#[derive(Resource, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource)]
+pub struct EntrySpot(pub String);
+
 #[allow(clippy::needless_pass_by_value)]
 #[allow(clippy::type_complexity)]
 fn handle_next_spot(world: &mut World) {
@@ -45,25 +67,87 @@ fn handle_next_spot(world: &mut World) {
         return;
     }
 
-    let next = world.remove_resource::<NextSpot>();
+    let mut next = world.remove_resource::<NextSpot>();
     let skip = world.remove_resource::<SkipAnimation>().is_some();
 
+    // A pending NextSpot wins, and its move is recorded in the history as usual
+    let back = world.remove_resource::<GoBack>().is_some() && next.is_none();
+
+    if back {
+        let previous = world.resource_mut::<SpotHistory>().pop();
+        next = Some(NextSpot(previous.unwrap_or_else(|| MAIN_CAMERA.to_owned())));
+    }
+
     if let Some(next) = next {
+        let from = world
+            .get_resource::<CurrentSpot>()
+            .and_then(CurrentSpot::name)
+            .map(str::to_owned);
+
         let mut state: SystemState<(
             CameraSpots,
-            Query<(&mut Transform, &mut Tween<Transform>), With<Camera>>,
+            CameraPaths,
+            Res<CameraSettings>,
+            Query<&CameraMotion>,
+            Query<
+                (
+                    Entity,
+                    &mut Transform,
+                    &mut Tween<Transform>,
+                    &mut Projection,
+                    Option<&mut Tween<Projection>>,
+                    Option<&TweenPath>,
+                ),
+                With<Camera>,
+            >,
         )> = SystemState::new(world);
 
-        let (spots, mut cameras) = state.get_mut(world);
+        let (spots, paths, settings, motions, mut cameras) = state.get_mut(world);
 
         if let Some(spot) = spots.get(&next.0) {
-            if let Ok((mut tf, mut animation)) = cameras.get_single_mut() {
+            if let Ok((camera, mut tf, mut animation, mut projection, zoom, current_path)) =
+                cameras.get_single_mut()
+            {
+                let mut path = None;
+
                 if skip {
                     *tf = spot.transform();
+                    animation.jump(spot.transform());
+
+                    *projection = spot.projection().clone();
+
+                    if let Some(mut zoom) = zoom {
+                        zoom.jump(spot.projection().clone());
+                    }
+                } else {
+                    let motion = motions.get(spot.entity()).ok();
+                    let (duration, easing) = CameraMotion::resolve(motion, &settings);
+
+                    // The Transform includes the look around and shake offsets, the Tween doesn't
+                    let start = animation.current(current_path);
+                    animation.animate(start, spot.transform(), duration, easing);
+
+                    if let Some(mut zoom) = zoom {
+                        let current = projection.clone();
+                        zoom.animate(current, spot.projection().clone(), duration, easing);
+                    }
+
+                    path = from.as_ref().and_then(|from| paths.get(from, &next.0));
                 }
 
-                animation.target = spot.transform();
+                match path {
+                    Some(points) => world.entity_mut(camera).insert(TweenPath::new(points)),
+                    None => world.entity_mut(camera).remove::<TweenPath>(),
+                };
+
                 world.insert_resource(CurrentSpot::new(spot));
+
+                if let (false, Some(from)) = (back, &from) {
+                    world.resource_mut::<SpotHistory>().visit(from, &next.0);
+                }
+
+                world.resource_mut::<CameraMoving>().start(&next.0);
+                world.send_event(CameraMoveStarted { from, to: next.0 });
             }
         } else {
             for entity in world.iter_entities().map(|e| e.id()) {
@@ -0,0 +1,120 @@
+use bevy::{
+    ecs::system::SystemParam,
+    prelude::*,
+};
+
+use crate::{
+    camera::IsCameraSpot,
+    PATH_PREFIX,
+};
+
+pub struct CameraPathPlugin;
+
+impl Plugin for CameraPathPlugin {
+    fn build(&self, app: &mut App) {
+        app ////
+            .register_type::<CameraPath>()
+            .register_type::<Vec<Vec3>>();
+    }
+}
+
+/// A component that defines the path the Camera follows when moving between two `CameraSpot`s.
+///
+/// The points are relative to the entity the component is on, so a curve in the scene can be
+/// sampled into a `CameraPath` from the Scene's spawn callback.
+/// The path is also used in reverse, when moving from `to` to `from`.
+///
+/// For simple paths, name empties after [`PATH_PREFIX`] instead.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct CameraPath {
+    /// The name of the spot the path starts at.
+    pub from: String,
+
+    /// The name of the spot the path ends at.
+    pub to: String,
+
+    /// The points between the two spots, in order.
+    pub points: Vec<Vec3>,
+}
+
+impl CameraPath {
+    /// Returns a new `CameraPath` between two spots, through the given points.
+    pub fn new(from: &str, to: &str, points: Vec<Vec3>) -> Self {
+        Self {
+            from: from.to_owned(),
+            to: to.to_owned(),
+            points,
+        }
+    }
+}
+
+/// `SystemParam` for finding the path between two `CameraSpot`s.
+#[derive(SystemParam)]
+pub struct CameraPaths<'w, 's> {
+    paths: Query<'w, 's, (&'static CameraPath, &'static GlobalTransform)>,
+    empties: Query<'w, 's, (&'static Name, &'static GlobalTransform), Without<IsCameraSpot>>,
+}
+
+impl CameraPaths<'_, '_> {
+    /// Returns the points between two spots, in world space, if a path has been authored between them.
+    pub fn get(&self, from: &str, to: &str) -> Option<Vec<Vec3>> {
+        self.components(from, to)
+            .or_else(|| Some(self.empties(from, to)))
+            .filter(|points| !points.is_empty())
+    }
+
+    fn components(&self, from: &str, to: &str) -> Option<Vec<Vec3>> {
+        for (path, gtf) in &self.paths {
+            let points = path.points.iter().map(|point| gtf.transform_point(*point));
+
+            if path.from == from && path.to == to {
+                return Some(points.collect());
+            }
+
+            if path.from == to && path.to == from {
+                return Some(points.rev().collect());
+            }
+        }
+
+        None
+    }
+
+    fn empties(&self, from: &str, to: &str) -> Vec<Vec3> {
+        let forward = format!("{PATH_PREFIX}{from}_{to}");
+        let backward = format!("{PATH_PREFIX}{to}_{from}");
+
+        let mut points = self.collect(&forward);
+        if !points.is_empty() {
+            return points;
+        }
+
+        points = self.collect(&backward);
+        points.reverse();
+
+        points
+    }
+
+    fn collect(&self, base: &str) -> Vec<Vec3> {
+        let mut points = self
+            .empties
+            .iter()
+            .filter_map(|(name, gtf)| Some((path_index(name, base)?, gtf.translation())))
+            .collect::<Vec<_>>();
+
+        points.sort_by_key(|(index, _)| *index);
+        points.into_iter().map(|(_, point)| point).collect()
+    }
+}
+
+/// Returns the position of an empty in the path named `base`, from its numeric suffix.
+fn path_index(name: &str, base: &str) -> Option<u32> {
+    let rest = name.strip_prefix(base)?;
+
+    if rest.is_empty() {
+        return Some(0);
+    }
+
+    rest.strip_prefix('.')?.parse().ok()
+}
@@ -2,7 +2,14 @@ use bevy::prelude::*;
 
 use crate::{
     camera::{
+        effects::CameraEffectsPlugin,
+        history::HistoryPlugin,
+        look::LookAroundPlugin,
+        motion::CameraMotionPlugin,
+        moving::CameraMovingPlugin,
+        neighbors::NeighborsPlugin,
         next::NextPlugin,
+        path::CameraPathPlugin,
         spot::CameraSpotPlugin,
     },
     BackToSpot,
@@ -16,6 +23,16 @@ impl Plugin for CameraPlugin {
         app ////
             .register_type::<BackToSpot>()
             .register_type::<Ignores>()
-            .add_plugins((CameraSpotPlugin, NextPlugin));
+            .add_plugins((
+                CameraEffectsPlugin,
+                CameraMotionPlugin,
+                CameraMovingPlugin,
+                CameraSpotPlugin,
+                HistoryPlugin,
+                CameraPathPlugin,
+                LookAroundPlugin,
+                NeighborsPlugin,
+                NextPlugin,
+            ));
     }
 }
@@ -2,6 +2,10 @@ use bevy::{
     ecs::system::SystemParam,
     prelude::*,
 };
+use serde_json::{
+    Map,
+    Value,
+};
 
 pub struct CameraSpotPlugin;
 
@@ -12,6 +16,7 @@ impl Plugin for CameraSpotPlugin {
             .register_type::<Option<CameraSpot>>()
             .register_type::<CameraSpot>()
             .register_type::<IsCameraSpot>()
+            .register_type::<FocusSpot>()
             ////
             .init_resource::<CurrentSpot>();
     }
@@ -35,18 +40,23 @@ impl CurrentSpot {
 
     /// Returns the `CameraSpot` the `CurrentSpot` is currently set to.
     ///
-    /// # Panics
-    /// If the Scene has not been set up yet, or there is no `MAIN_CAMERA` spot.
-    pub fn get(&self) -> &CameraSpot {
-        self.spot.as_ref().unwrap()
+    /// Returns `None` if the Scene has not been set up yet, or there is no `MAIN_CAMERA` spot.
+    pub fn get(&self) -> Option<&CameraSpot> {
+        self.spot.as_ref()
     }
 
     /// Returns the name of the `CameraSpot` the `CurrentSpot` is currently set to.
     ///
-    /// # Panics
-    /// If the Scene has not been set up yet, or there is no `MAIN_CAMERA` spot.
-    pub fn name(&self) -> &str {
-        self.get().name()
+    /// Returns `None` if the Scene has not been set up yet, or there is no `MAIN_CAMERA` spot.
+    pub fn name(&self) -> Option<&str> {
+        self.get().map(CameraSpot::name)
+    }
+
+    /// Returns the entity of the `CameraSpot` the `CurrentSpot` is currently set to.
+    ///
+    /// Returns `None` if the Scene has not been set up yet, or there is no `MAIN_CAMERA` spot.
+    pub fn entity(&self) -> Option<Entity> {
+        self.get().map(CameraSpot::entity)
     }
 
     /// Returns true if the `CurrentSpot` is set.
@@ -56,12 +66,17 @@ impl CurrentSpot {
 }
 
 /// A `CameraSpot` - a location the Camera might be at in the scene.
+///
+/// Besides its location, a spot keeps the projection of the camera it was imported from,
+/// so each spot can have its own field of view, or be orthographic.
 #[derive(Debug, Reflect)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CameraSpot {
     name: String,
     entity: Entity,
     transform: Transform,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    projection: Projection,
 }
 
 impl CameraSpot {
@@ -71,9 +86,17 @@ impl CameraSpot {
             name: name.to_owned(),
             entity,
             transform,
+            projection: Projection::default(),
         }
     }
 
+    /// Set the projection of the `CameraSpot`.
+    #[must_use]
+    pub fn with_projection(mut self, projection: Projection) -> Self {
+        self.projection = projection;
+        self
+    }
+
     /// Returns the name of the `CameraSpot`.
     pub fn name(&self) -> &str {
         &self.name
@@ -93,6 +116,11 @@ impl CameraSpot {
     pub fn transform(&self) -> Transform {
         self.transform
     }
+
+    /// Returns the projection of the `CameraSpot`.
+    pub fn projection(&self) -> &Projection {
+        &self.projection
+    }
 }
 
 #[derive(Component, Debug, Default, Reflect)]
@@ -100,46 +128,122 @@ impl CameraSpot {
 #[reflect(Component)]
 pub struct IsCameraSpot;
 
+/// A component that links an interactive to the `CameraSpot` the Camera moves to when it is interacted with.
+///
+/// Any number of interactives can share the same spot.
+/// Can also be set from the glTF extras (custom properties) of the interactive, with a `focus_spot` string naming the camera.
+///
+/// Interactives without a `FocusSpot` use the camera named `Camera_ENTITY_NAME`, if there is one.
+#[derive(Component, Clone, Debug, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub enum FocusSpot {
+    /// The spot with the given entity.
+    Entity(Entity),
+
+    /// The spot with the given name.
+    Name(String),
+}
+
+impl FocusSpot {
+    /// Returns a new `FocusSpot` for the spot with the given name.
+    pub fn named(name: &str) -> Self {
+        Self::Name(name.to_owned())
+    }
+
+    /// Returns true if the `FocusSpot` refers to the given spot.
+    pub fn is(&self, spot: &CameraSpot) -> bool {
+        match self {
+            Self::Entity(entity) => *entity == spot.entity(),
+            Self::Name(name) => name == spot.name(),
+        }
+    }
+
+    pub(crate) fn from_extras(extras: &Map<String, Value>) -> Option<Self> {
+        extras
+            .get("focus_spot")
+            .and_then(Value::as_str)
+            .map(Self::named)
+    }
+}
+
+// For Reflect
+impl Default for FocusSpot {
+    fn default() -> Self {
+        Self::Entity(Entity::PLACEHOLDER)
+    }
+}
+
 /// `SystemParam` for retrieving `CameraSpots` for entities or from names.
 #[derive(SystemParam)]
 pub struct CameraSpots<'w, 's> {
-    named: Query<'w, 's, (Entity, &'static Name), Without<IsCameraSpot>>,
-    spots: Query<'w, 's, (Entity, &'static Name, &'static GlobalTransform), With<IsCameraSpot>>,
+    named:
+        Query<'w, 's, (Entity, &'static Name, Option<&'static FocusSpot>), Without<IsCameraSpot>>,
+    spots: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static Name,
+            &'static GlobalTransform,
+            Option<&'static Projection>,
+        ),
+        With<IsCameraSpot>,
+    >,
 }
 
 impl<'w, 's> CameraSpots<'w, 's> {
     /// Given a spot's name, retrieve the associated `CameraSpot`.
     pub fn get(&self, spot: &str) -> Option<CameraSpot> {
-        for (entity, spot_name, gtf) in &self.spots {
-            if spot == spot_name.as_str() {
-                return Some(CameraSpot::new(spot_name, entity, gtf.compute_transform()));
-            }
-        }
+        let (entity, ..) = self
+            .spots
+            .iter()
+            .find(|(_, name, ..)| name.as_str() == spot)?;
+
+        self.get_entity(entity)
+    }
+
+    /// Given a spot's entity, retrieve the associated `CameraSpot`.
+    pub fn get_entity(&self, entity: Entity) -> Option<CameraSpot> {
+        let (entity, name, gtf, projection) = self.spots.get(entity).ok()?;
+
+        let spot = CameraSpot::new(name, entity, gtf.compute_transform())
+            .with_projection(projection.cloned().unwrap_or_default());
 
-        None
+        Some(spot)
     }
 
-    /// Given an interactive entity, retrieve a `CameraSpot` from the interactive's name.
+    /// Given an interactive entity, retrieve the `CameraSpot` it is linked to.
     ///
-    /// Only works if the name of the `CameraSpot` matches `Camera_ENTITY_NAME`
+    /// Uses the interactive's [`FocusSpot`] if it has one,
+    /// otherwise only works if the name of the `CameraSpot` matches `Camera_ENTITY_NAME`.
     pub fn for_interactive(&self, entity: Entity) -> Option<CameraSpot> {
-        if let Ok((_, name)) = self.named.get(entity) {
-            return self.get(format! {"Camera_{name}"}.as_str());
+        match self.named.get(entity) {
+            Ok((_, _, Some(FocusSpot::Entity(spot)))) => self.get_entity(*spot),
+            Ok((_, _, Some(FocusSpot::Name(spot)))) => self.get(spot),
+            Ok((_, name, None)) => self.get(format! {"Camera_{name}"}.as_str()),
+            Err(_) => None,
         }
-
-        None
     }
 
     /// Given a `CameraSpot`, retrieve the associated `Interactive` entity.
+    ///
+    /// If more than one interactive is linked to the spot, any one of them is returned, see [`CameraSpots::interactives_for`].
     pub fn for_spot(&self, spot: &CameraSpot) -> Option<Entity> {
-        if let Some((_, target)) = spot.name().split_once("Camera_") {
-            for (entity, name) in &self.named {
-                if name.as_str() == target {
-                    return Some(entity);
-                }
-            }
-        }
+        self.interactives_for(spot).into_iter().next()
+    }
 
-        None
+    /// Given a `CameraSpot`, retrieve every `Interactive` entity linked to it.
+    pub fn interactives_for(&self, spot: &CameraSpot) -> Vec<Entity> {
+        let target = spot.name().split_once("Camera_").map(|(_, target)| target);
+
+        self.named
+            .iter()
+            .filter(|(_, name, focus)| match focus {
+                Some(focus) => focus.is(spot),
+                None => target == Some(name.as_str()),
+            })
+            .map(|(entity, _, _)| entity)
+            .collect()
     }
 }
@@ -0,0 +1,278 @@
+use std::{
+    collections::HashMap,
+    marker::PhantomData,
+};
+
+use bevy::{
+    ecs::schedule::States,
+    prelude::*,
+};
+
+use crate::{
+    camera::{
+        EntrySpot,
+        NextSpot,
+    },
+    CurrentSpot,
+    Inventory,
+    Item,
+};
+
+pub struct CharacterPlugin<S>(PhantomData<S>);
+
+impl<S> Default for CharacterPlugin<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S> Plugin for CharacterPlugin<S>
+where
+    S: States,
+{
+    fn build(&self, app: &mut App) {
+        app ////
+            .register_type::<CurrentCharacter>()
+            .register_type::<Characters>()
+            .register_type::<Character>()
+            .register_type::<HashMap<String, Character>>()
+            ////
+            .init_resource::<CurrentCharacter>()
+            .init_resource::<Characters>()
+            .init_resource::<CharacterScenes<S>>()
+            ////
+            .add_systems(Last, switch_character::<S>);
+    }
+}
+
+/// Resource that specifies which character the player is controlling, if the game has more than one.
+///
+/// The [`Inventory`] resource always holds the items of the current character,
+/// while the other characters are stored in [`Characters`].
+/// Games with a single character can ignore this entirely.
+///
+/// If you want to switch to another character, use `NextCharacter` or `Action::SwitchCharacter`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct CurrentCharacter {
+    name: Option<String>,
+}
+
+impl CurrentCharacter {
+    /// Create a new `CurrentCharacter` with the given name.
+    ///
+    /// Insert it at startup, so the initial `Inventory` belongs to that character.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: Some(name.to_owned()),
+        }
+    }
+
+    /// Returns the name of the current character, if any.
+    pub fn get(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns true if a character has been set.
+    pub fn is_some(&self) -> bool {
+        self.name.is_some()
+    }
+}
+
+/// Insert this resource to switch to the character with the given name.
+///
+/// The current character's inventory, scene and `CameraSpot` are stored,
+/// and the ones stored for the new character are restored.
+/// The character must be registered in [`Characters`] first, otherwise a warning is logged and nothing changes.
+///
+/// Swapping the `Inventory` sends `ItemAdded` and `ItemRemoved` events for the difference between the two inventories.
+#[derive(Resource, Default, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource)]
+pub struct NextCharacter(pub String);
+
+/// A character that the player is not controlling right now.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Reflect)]
+pub struct Character {
+    /// The items held by the character.
+    pub inventory: Inventory,
+
+    /// The name of the `CameraSpot` the character was at, if any.
+    pub spot: Option<String>,
+}
+
+impl Character {
+    /// Returns a new `Character` with an empty inventory.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the inventory of the character.
+    #[must_use]
+    pub fn inventory(mut self, inventory: Inventory) -> Self {
+        self.inventory = inventory;
+        self
+    }
+
+    /// Set the `CameraSpot` the character starts at.
+    #[must_use]
+    pub fn spot(mut self, name: &str) -> Self {
+        self.spot = Some(name.to_owned());
+        self
+    }
+}
+
+/// A resource that stores every character that the player is not controlling right now.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct Characters {
+    map: HashMap<String, Character>,
+}
+
+impl Characters {
+    /// Register a character, replacing any existing character with the same name.
+    pub fn insert(&mut self, name: &str, character: Character) -> &mut Self {
+        self.map.insert(name.to_owned(), character);
+        self
+    }
+
+    /// Returns a stored character, given its name.
+    pub fn get(&self, name: &str) -> Option<&Character> {
+        self.map.get(name)
+    }
+
+    /// Returns a mutable reference to a stored character, given its name.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Character> {
+        self.map.get_mut(name)
+    }
+
+    /// Returns true if a character with the given name is stored.
+    pub fn contains(&self, name: &str) -> bool {
+        self.map.contains_key(name)
+    }
+
+    /// Move `count` units of an item from an inventory to a stored character's inventory.
+    ///
+    /// Returns false, moving nothing, if the character does not exist,
+    /// too few units are held or the character's inventory is full.
+    pub fn give(&mut self, from: &mut Inventory, item: &Item, count: u32, to: &str) -> bool {
+        let Some(character) = self.map.get_mut(to) else {
+            return false;
+        };
+
+        if from.count(item) < count || !character.inventory.add(item.clone(), count) {
+            return false;
+        }
+
+        from.remove(item, count);
+
+        true
+    }
+}
+
+/// A resource that stores the scene each character was in, for characters the player is not controlling right now.
+///
+/// Switching to a character in another scene changes the state to that scene.
+///
+/// Since the state type is defined by your game, register `CharacterScenes<S>` with `App::register_type` to reflect it.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct CharacterScenes<S> {
+    map: HashMap<String, S>,
+}
+
+impl<S> Default for CharacterScenes<S> {
+    fn default() -> Self {
+        Self {
+            map: HashMap::new(),
+        }
+    }
+}
+
+impl<S> CharacterScenes<S> {
+    /// Set the scene a stored character is in.
+    pub fn insert(&mut self, name: &str, scene: S) -> &mut Self {
+        self.map.insert(name.to_owned(), scene);
+        self
+    }
+
+    /// Returns the scene a stored character is in, if any.
+    pub fn get(&self, name: &str) -> Option<&S> {
+        self.map.get(name)
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::too_many_arguments)]
+fn switch_character<S: States>(
+    mut commands: Commands,
+    next: Option<Res<NextCharacter>>,
+    mut current: ResMut<CurrentCharacter>,
+    mut characters: ResMut<Characters>,
+    mut scenes: ResMut<CharacterScenes<S>>,
+    mut inventory: ResMut<Inventory>,
+    at_spot: Res<CurrentSpot>,
+    state: Option<Res<State<S>>>,
+) {
+    let Some(next) = next else {
+        return;
+    };
+
+    commands.remove_resource::<NextCharacter>();
+
+    if current.get() == Some(next.0.as_str()) {
+        return;
+    }
+
+    let Some(target) = characters.map.remove(&next.0) else {
+        warn!(
+            "Could not switch to character {:?}, it is not in Characters",
+            next.0
+        );
+        return;
+    };
+
+    let held = std::mem::replace(&mut *inventory, target.inventory);
+
+    if let Some(name) = current.name.take() {
+        let spot = at_spot.name().map(str::to_owned);
+
+        characters.insert(&name, Character {
+            inventory: held,
+            spot,
+        });
+
+        if let Some(state) = &state {
+            scenes.insert(&name, state.get().clone());
+        }
+    } else if !held.is_empty() {
+        warn!(
+            "Switched to character {:?} without a CurrentCharacter, the previous Inventory was dropped",
+            next.0
+        );
+    }
+
+    let mut entering = false;
+
+    if let Some(scene) = scenes.map.remove(&next.0) {
+        if state.is_some_and(|state| *state.get() != scene) {
+            commands.insert_resource(NextState(Some(scene)));
+            entering = true;
+        }
+    }
+
+    // The spots of the new scene don't exist yet, so it starts there once it's ready
+    if let Some(spot) = target.spot {
+        if entering {
+            commands.insert_resource(EntrySpot(spot));
+        } else {
+            commands.insert_resource(NextSpot(spot));
+        }
+    }
+
+    current.name = Some(next.0.clone());
+}
@@ -1,2 +1,14 @@
 /// The main camera spot in a scene is always named `Camera_Main`.
 pub const MAIN_CAMERA: &str = "Camera_Main";
+
+/// Prefix of the names of empties that make up a camera path between two spots.
+///
+/// The empties for a path from `Camera_Main` to `Camera_Desk` are named `Path_Camera_Main_Camera_Desk`,
+/// `Path_Camera_Main_Camera_Desk.001`, `Path_Camera_Main_Camera_Desk.002` and so on, in order.
+/// This is what Blender names duplicated objects, so a path can be authored by duplicating an empty.
+pub const PATH_PREFIX: &str = "Path_";
+
+/// Height of the inventory strip along the bottom of the window, in logical pixels.
+///
+/// Clicks inside the strip never interact with the scene.
+pub const INVENTORY_HEIGHT: f32 = 100.0;
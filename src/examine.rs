@@ -0,0 +1,271 @@
+use std::{
+    collections::HashMap,
+    sync::Arc,
+};
+
+use bevy::{
+    ecs::{
+        system::EntityCommands,
+        world::EntityRef,
+    },
+    input::mouse::MouseMotion,
+    prelude::*,
+};
+
+use crate::{
+    animation::Tween,
+    scene::SceneManager,
+    textdisplay::{
+        Message,
+        TextDisplay,
+    },
+    CurrentSpot,
+    Cursor,
+    DraggingItem,
+    Item,
+    ItemRegistry,
+    INVENTORY_HEIGHT,
+};
+
+pub struct ExaminePlugin;
+
+impl Plugin for ExaminePlugin {
+    fn build(&self, app: &mut App) {
+        app ////
+            .register_type::<ExamineSettings>()
+            ////
+            .init_resource::<ExamineSettings>()
+            .init_resource::<ExamineHooks>()
+            ////
+            .add_systems(PreUpdate, rotate_examined)
+            .add_systems(Last, (start_examine, end_examine).chain());
+    }
+}
+
+/// Insert this resource to take a closer look at an item.
+///
+/// If the item has a model in the [`ItemRegistry`], the model is spawned in front of the camera
+/// and can be rotated by dragging the mouse. Its examine text is shown as a message, if it has one.
+///
+/// Going back (clicking the inventory strip without dragging an item) puts the model away
+/// and returns to the `CameraSpot` the player was at before.
+#[derive(Resource, Clone, Debug)]
+pub struct ExamineItem(pub Item);
+
+/// Resource that exists while the player is examining an item model.
+#[derive(Resource, Debug)]
+pub struct Examining {
+    item: Item,
+    model: Entity,
+    previous: Option<String>,
+    pressed: bool,
+    dragged: f32,
+}
+
+impl Examining {
+    /// Returns the item being examined.
+    pub fn item(&self) -> &Item {
+        &self.item
+    }
+
+    /// Returns the root entity of the spawned model.
+    pub fn model(&self) -> Entity {
+        self.model
+    }
+
+    /// Returns the name of the `CameraSpot` to return to when done examining, if any.
+    pub fn previous(&self) -> Option<&str> {
+        self.previous.as_deref()
+    }
+
+    /// Returns true if the current mouse press is rotating the model rather than clicking on it.
+    pub(crate) fn dragged(&self) -> bool {
+        self.dragged > DRAG_THRESHOLD
+    }
+}
+
+/// Distance the mouse has to move while pressed before a click turns into a drag, in logical pixels.
+const DRAG_THRESHOLD: f32 = 4.0;
+
+/// A resource that configures how examined item models are shown.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct ExamineSettings {
+    /// Distance between the camera and the model.
+    pub distance: f32,
+
+    /// Rotation applied for each logical pixel of mouse movement, in radians.
+    pub rotate_speed: f32,
+}
+
+impl Default for ExamineSettings {
+    fn default() -> Self {
+        Self {
+            distance: 0.5,
+            rotate_speed: 0.01,
+        }
+    }
+}
+
+/// Marker component added to every entity spawned from an examined item model.
+#[derive(Component, Debug, Default)]
+pub struct ExaminePart;
+
+type ExamineHook = Arc<dyn Fn(&EntityRef, &mut EntityCommands) + Send + Sync + 'static>;
+
+/// A resource that stores callbacks that fire for each entity spawned from an item's model.
+///
+/// Use it to add colliders and interactives to parts of the model, like a battery cover that can be opened.
+#[derive(Resource, Default)]
+pub struct ExamineHooks {
+    map: HashMap<Item, ExamineHook>,
+}
+
+impl ExamineHooks {
+    /// Register the callback for an item's model, replacing any existing callback for it.
+    pub fn insert<F>(&mut self, id: &str, hook: F) -> &mut Self
+    where
+        F: Fn(&EntityRef, &mut EntityCommands) + Send + Sync + 'static,
+    {
+        self.map.insert(Item::new(id), Arc::new(hook));
+        self
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::too_many_arguments)]
+fn start_examine(
+    mut commands: Commands,
+    mut manager: SceneManager,
+    mut display: TextDisplay,
+    registry: Res<ItemRegistry>,
+    hooks: Res<ExamineHooks>,
+    settings: Res<ExamineSettings>,
+    at_spot: Res<CurrentSpot>,
+    next: Option<Res<ExamineItem>>,
+    examining: Option<Res<Examining>>,
+    cameras: Query<&Tween<Transform>, With<Camera>>,
+) {
+    let Some(next) = next else {
+        return;
+    };
+
+    commands.remove_resource::<ExamineItem>();
+
+    let item = next.0.clone();
+    let info = registry.get(&item);
+
+    if let Some(text) = info.and_then(|info| info.examine.clone()) {
+        display.show(Message::Text(text));
+    }
+
+    let Some(model) = info.and_then(|info| info.model.clone()) else {
+        return;
+    };
+
+    let Ok(camera) = cameras.get_single() else {
+        return;
+    };
+
+    let mut previous = at_spot.name().map(str::to_owned);
+
+    if let Some(examining) = examining {
+        commands.entity(examining.model).despawn_recursive();
+        previous.clone_from(&examining.previous);
+    }
+
+    let hook = hooks.map.get(&item).cloned();
+
+    let mut bundle = manager.load_model(model, move |entity, commands| {
+        commands.insert(ExaminePart);
+
+        if let Some(hook) = &hook {
+            hook(entity, commands);
+        }
+    });
+
+    let view = camera.target;
+    bundle.0.transform =
+        Transform::from_translation(view.translation + view.forward() * settings.distance)
+            .with_rotation(view.rotation);
+
+    let entity = commands
+        .spawn((
+            Name::new(format!("Examine {}", item.as_str())),
+            ExaminePart,
+            bundle,
+        ))
+        .id();
+
+    commands.insert_resource(Examining {
+        item,
+        model: entity,
+        previous,
+        pressed: false,
+        dragged: 0.0,
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn end_examine(
+    mut commands: Commands,
+    examining: Option<Res<Examining>>,
+    models: Query<Entity, (With<Handle<Scene>>, With<ExaminePart>)>,
+) {
+    match examining {
+        Some(examining) => {
+            if !models.contains(examining.model) {
+                // The model was despawned along with the scene
+                commands.remove_resource::<Examining>();
+            }
+        }
+        None => {
+            for entity in &models {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::too_many_arguments)]
+fn rotate_examined(
+    input: Res<Input<MouseButton>>,
+    cursor: Res<Cursor>,
+    dragging: Res<DraggingItem>,
+    settings: Res<ExamineSettings>,
+    examining: Option<ResMut<Examining>>,
+    mut motion: EventReader<MouseMotion>,
+    windows: Query<&Window>,
+    cameras: Query<&Transform, (With<Camera>, Without<ExaminePart>)>,
+    mut models: Query<&mut Transform, With<ExaminePart>>,
+) {
+    let delta = motion.read().map(|event| event.delta).sum::<Vec2>();
+
+    let Some(mut examining) = examining else {
+        return;
+    };
+
+    if input.just_pressed(MouseButton::Left) {
+        let height = windows
+            .get_single()
+            .map_or(f32::MAX, |window| window.height());
+
+        examining.pressed = cursor.position().y < height - INVENTORY_HEIGHT;
+        examining.dragged = 0.0;
+    }
+
+    if !input.pressed(MouseButton::Left) || !examining.pressed || dragging.is_dragging() {
+        return;
+    }
+
+    examining.dragged += delta.length();
+
+    if let (Ok(camera), Ok(mut tf)) = (cameras.get_single(), models.get_mut(examining.model)) {
+        let yaw = Quat::from_axis_angle(camera.up(), delta.x * settings.rotate_speed);
+        let pitch = Quat::from_axis_angle(camera.right(), delta.y * settings.rotate_speed);
+
+        tf.rotation = (yaw * pitch * tf.rotation).normalize();
+    }
+}
@@ -0,0 +1,23 @@
+use bevy::{
+    ecs::world::EntityRef,
+    gltf::GltfExtras,
+    prelude::*,
+};
+use serde_json::{
+    Map,
+    Value,
+};
+
+/// Returns the glTF extras (custom properties, in Blender) of an entity as a JSON object, if it has any.
+pub(crate) fn gltf_extras(entity: &EntityRef) -> Option<Map<String, Value>> {
+    let extras = entity.get::<GltfExtras>()?;
+
+    match serde_json::from_str(&extras.value) {
+        Ok(Value::Object(map)) => Some(map),
+        Ok(_) => None,
+        Err(err) => {
+            warn!("Could not parse glTF extras {:?}: {}", extras.value, err);
+            None
+        }
+    }
+}
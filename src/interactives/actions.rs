@@ -15,23 +15,77 @@ pub enum Action<State> {
     /// Add an item to the player's inventory.
     AddItem(Item),
 
+    /// Add a number of units of an item to the player's inventory.
+    AddItems(Item, u32),
+
+    /// Remove a single unit of an item from the player's inventory.
+    RemoveItem(Item),
+
     /// Play an animation.
     Animation(String),
 
     /// Play an audio clip.
     Audio(String),
 
+    /// Move a single unit of an item from the player's inventory into the [`Container`](crate::Container) with the given id.
+    Store(String, Item),
+
+    /// Move every unit of an item from the [`Container`](crate::Container) with the given id into the player's inventory.
+    Take(String, Item),
+
+    /// Show what is inside the [`Container`](crate::Container) with the given id, without taking anything.
+    Open(String),
+
+    /// Move everything from the [`Container`](crate::Container) with the given id into the player's inventory.
+    TakeAll(String),
+
+    /// Give a single unit of an item to another character, see [`Characters`](crate::Characters).
+    GiveItem(Item, String),
+
+    /// Switch to the character with the given name, see [`CurrentCharacter`](crate::CurrentCharacter).
+    SwitchCharacter(String),
+
+    /// Take a closer look at an item, see [`ExamineItem`](crate::ExamineItem).
+    Examine(Item),
+
     /// Send a message.
     Message(Message),
 
+    /// Set a key in the `WorldState` to the given value.
+    Set(String, String),
+
     /// Change the current state to the given state.
     Transition(State),
 
+    /// Change the current state to the given state, starting the new scene at the `CameraSpot` with the given name.
+    TransitionTo(State, String),
+
     /// Move to a CameraSpot with the given name.
     Move(String),
 
     /// Jump to a CameraSpot with the given name, skipping animation.
     Jump(String),
+
+    /// Shake the Camera, see [`CameraShake`](crate::CameraShake).
+    CameraShake {
+        /// How far the Camera moves away from its position, in world units.
+        intensity: f32,
+
+        /// How long the shake lasts, in seconds.
+        duration: f32,
+    },
+
+    /// Fade the screen out to the given color, over the given duration in seconds.
+    FadeOut(Color, f32),
+
+    /// Fade the screen back in, over the given duration in seconds.
+    FadeIn(f32),
+
+    /// Zoom the Camera to the given field of view, over the given duration in seconds, see [`Zoom`](crate::Zoom).
+    Zoom(f32, f32),
+
+    /// Go back to the previous `CameraSpot`, see [`SpotHistory`](crate::SpotHistory).
+    Back,
 }
 
 impl<State> Action<State> {
@@ -41,6 +95,14 @@ impl<State> Action<State> {
     }
 }
 
+impl<T> Action<T> {
+    /// Returns an `Action::Set` that sets a key in the `WorldState` to the given value.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn set<V: ToString>(key: &str, value: V) -> Self {
+        Action::Set(key.to_owned(), value.to_string())
+    }
+}
+
 impl<T> From<Action<T>> for Vec<Action<T>> {
     fn from(action: Action<T>) -> Self {
         vec![action]
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::{
+    interactives::{
+        simple::NoState,
+        Action,
+        Interactive,
+        ItemRef,
+    },
+    inventory::{
+        Inventory,
+        Item,
+    },
+    state::WorldState,
+    AdventureScene,
+};
+
+pub struct ContainerPlugin;
+
+impl Plugin for ContainerPlugin {
+    fn build(&self, app: &mut App) {
+        app ////
+            .register_type::<Container>()
+            .register_type::<Containers>()
+            .register_type::<HashMap<String, Inventory>>()
+            ////
+            .init_resource::<Containers>();
+    }
+}
+
+/// A preset `Interactive` that stores items in the world, like a drawer or a shelf.
+///
+/// Dragging an item onto it puts a single unit of the item inside.
+/// Interacting with it shows what is inside, and interacting again moves everything back to the player's inventory.
+/// Use `Action::Take` to take back a single item instead.
+/// The contents are kept in the [`Containers`] resource, so they persist when the scene is left.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
+pub struct Container {
+    id: String,
+    open: bool,
+}
+
+impl Container {
+    /// Returns a new `Container` whose contents are stored under the given id.
+    ///
+    /// The id must be unique across all scenes.
+    pub fn new(id: &str) -> Self {
+        Self {
+            id: id.to_owned(),
+            open: false,
+        }
+    }
+
+    /// Returns a new `Container` for the entity with the given name in a Scene.
+    pub fn build<S: AdventureScene>(name: &str) -> Self {
+        Self::new(&format!("{}/{name}", S::scene()))
+    }
+
+    /// Returns the id the contents of the container are stored under.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl Interactive for Container {
+    type State = NoState;
+
+    fn use_item(
+        &mut self,
+        _state: &mut ResMut<WorldState>,
+        item: &mut ItemRef,
+    ) -> Vec<Action<Self::State>> {
+        Action::Store(self.id.clone(), Item::new(item.as_str())).single()
+    }
+
+    fn interact(&mut self, _state: &mut ResMut<WorldState>) -> Vec<Action<Self::State>> {
+        self.open = !self.open;
+
+        if self.open {
+            Action::Open(self.id.clone()).single()
+        } else {
+            Action::TakeAll(self.id.clone()).single()
+        }
+    }
+}
+
+/// A resource that stores the contents of every [`Container`], by id.
+///
+/// Containers can be filled in advance (or given a capacity) by inserting an `Inventory` for their id.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct Containers {
+    map: HashMap<String, Inventory>,
+}
+
+impl Containers {
+    /// Set the contents of a container, replacing any existing contents.
+    pub fn insert(&mut self, id: &str, inventory: Inventory) -> &mut Self {
+        self.map.insert(id.to_owned(), inventory);
+        self
+    }
+
+    /// Returns the contents of a container, if anything has been stored in it.
+    pub fn get(&self, id: &str) -> Option<&Inventory> {
+        self.map.get(id)
+    }
+
+    /// Move `count` units of an item from an inventory into a container.
+    ///
+    /// Returns false, moving nothing, if too few units are held or the container is full.
+    pub fn store(&mut self, from: &mut Inventory, item: &Item, count: u32, id: &str) -> bool {
+        if from.count(item) < count {
+            return false;
+        }
+
+        // A new container has no capacity, so it is only created once the item is stored
+        let container = self.map.entry(id.to_owned()).or_default();
+
+        if !container.add(item.clone(), count) {
+            return false;
+        }
+
+        from.remove(item, count);
+
+        true
+    }
+
+    /// Move every unit of an item from a container into an inventory.
+    ///
+    /// Returns how many units were moved.
+    pub fn take(&mut self, to: &mut Inventory, item: &Item, id: &str) -> u32 {
+        let Some(container) = self.map.get_mut(id) else {
+            return 0;
+        };
+
+        let count = container.count(item);
+
+        if count == 0 || !to.add(item.clone(), count) {
+            return 0;
+        }
+
+        container.remove(item, count);
+
+        count
+    }
+}
@@ -0,0 +1,196 @@
+use bevy::{
+    ecs::{
+        schedule::States,
+        system::SystemParam,
+    },
+    prelude::*,
+};
+
+use crate::{
+    camera::{
+        CameraShake,
+        EntrySpot,
+        GoBack,
+        NextSpot,
+        ScreenFade,
+        SkipAnimation,
+        Zoom,
+    },
+    character::{
+        Characters,
+        NextCharacter,
+    },
+    commands::CommandsExt,
+    examine::ExamineItem,
+    interactives::{
+        Action,
+        Containers,
+    },
+    inventory::{
+        Inventory,
+        Item,
+        ItemRegistry,
+    },
+    state::WorldState,
+    textdisplay::{
+        Message,
+        TextDisplay,
+    },
+};
+
+/// `SystemParam` that runs Actions.
+///
+/// This is what runs the Actions returned by Interactives, and can be used to run Actions from any other system.
+#[derive(SystemParam)]
+pub struct ActionDispatcher<'w, 's> {
+    pub(crate) commands: CommandsExt<'w, 's>,
+    pub(crate) display: TextDisplay<'w, 's>,
+
+    pub(crate) inventory: ResMut<'w, Inventory>,
+    pub(crate) characters: ResMut<'w, Characters>,
+    pub(crate) containers: ResMut<'w, Containers>,
+    pub(crate) registry: Res<'w, ItemRegistry>,
+    pub(crate) state: ResMut<'w, WorldState>,
+}
+
+impl<'w, 's> ActionDispatcher<'w, 's> {
+    /// Run the given Actions, in order.
+    pub fn run<S: States>(&mut self, actions: Vec<Action<S>>) {
+        for action in actions {
+            match action {
+                Action::AddItem(item) => self.add_items(item, 1),
+                Action::AddItems(item, count) => self.add_items(item, count),
+                Action::RemoveItem(item) => {
+                    self.inventory.remove(&item, 1);
+                }
+                Action::Animation(name) => {
+                    self.commands.play_animation(&name);
+                }
+                Action::Audio(name) => {
+                    self.commands.play_audio(&name);
+                }
+                Action::Store(id, item) => {
+                    if self.containers.store(&mut self.inventory, &item, 1, &id) {
+                        self.display.show(Message::ItemStored(item));
+                    } else {
+                        self.display.show(Message::InvalidItemUsed);
+                    }
+                }
+                Action::Take(id, item) => {
+                    let count = self.containers.take(&mut self.inventory, &item, &id);
+
+                    if count > 0 {
+                        self.display.show(Message::ItemPickup(item, count));
+                    }
+                }
+                Action::Open(id) => {
+                    let items = self
+                        .containers
+                        .get(&id)
+                        .map(|container| container.items().cloned().collect())
+                        .unwrap_or_default();
+
+                    self.display.show(Message::ContainerContents(items));
+                }
+                Action::TakeAll(id) => self.take_all(&id),
+                Action::GiveItem(item, to) => {
+                    if !self.characters.give(&mut self.inventory, &item, 1, &to) {
+                        warn!("Could not give Item {:?} to {:?}", item.as_str(), to);
+                    }
+                }
+                Action::SwitchCharacter(name) => {
+                    self.commands.insert_resource(NextCharacter(name));
+                }
+                Action::Examine(item) => self.commands.insert_resource(ExamineItem(item)),
+                Action::Message(text) => self.display.show(text),
+                Action::Set(key, value) => self.state.insert(&key, value),
+                Action::Transition(state) => {
+                    self.commands.insert_resource(NextState(Some(state)));
+                }
+                Action::TransitionTo(state, spot) => {
+                    self.commands.insert_resource(NextState(Some(state)));
+                    self.commands.insert_resource(EntrySpot(spot));
+                }
+                Action::Move(name) => self.commands.insert_resource(NextSpot(name)),
+                Action::Jump(name) => {
+                    self.commands.insert_resource(NextSpot(name));
+                    self.commands.insert_resource(SkipAnimation);
+                }
+                Action::CameraShake {
+                    intensity,
+                    duration,
+                } => {
+                    self.commands
+                        .insert_resource(CameraShake::new(intensity, duration));
+                }
+                Action::FadeOut(color, duration) => {
+                    self.commands.add(move |world: &mut World| {
+                        world.resource_mut::<ScreenFade>().fade_out(color, duration);
+                    });
+                }
+                Action::FadeIn(duration) => {
+                    self.commands.add(move |world: &mut World| {
+                        world.resource_mut::<ScreenFade>().fade_in(duration);
+                    });
+                }
+                Action::Zoom(fov, duration) => {
+                    self.commands.insert_resource(Zoom { fov, duration });
+                }
+                Action::Back => self.commands.insert_resource(GoBack),
+            }
+        }
+    }
+
+    /// Returns the [`CommandsExt`] used to run Actions.
+    pub fn commands(&mut self) -> &mut CommandsExt<'w, 's> {
+        &mut self.commands
+    }
+
+    /// Returns the player's [`Inventory`].
+    pub fn inventory(&mut self) -> &mut Inventory {
+        &mut self.inventory
+    }
+
+    /// Returns the [`WorldState`].
+    pub fn state(&mut self) -> &mut ResMut<'w, WorldState> {
+        &mut self.state
+    }
+
+    /// Send a message.
+    pub fn show(&mut self, message: Message) {
+        self.display.show(message);
+    }
+
+    fn take_all(&mut self, id: &str) {
+        let items = self
+            .containers
+            .get(id)
+            .map(|container| container.items().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        let taken = items
+            .into_iter()
+            .filter(|item| self.containers.take(&mut self.inventory, item, id) > 0)
+            .collect();
+
+        self.display.show(Message::ItemsTaken(taken));
+    }
+
+    fn add_items(&mut self, item: Item, count: u32) {
+        if !self.registry.is_empty() && !self.registry.contains(&item) {
+            warn!(
+                "Added Item {:?} which is not in the ItemRegistry",
+                item.as_str()
+            );
+        }
+
+        if self.inventory.add(item.clone(), count) {
+            self.display.show(Message::ItemPickup(item, count));
+        } else {
+            warn!(
+                "Could not add Item {:?}, the Inventory is full",
+                item.as_str()
+            );
+        }
+    }
+}
@@ -2,6 +2,14 @@ use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
 
 use crate::{
+    camera::{
+        effects::shake_camera,
+        look::look_around,
+    },
+    examine::{
+        ExaminePart,
+        Examining,
+    },
     interactives::ray::Ray3d,
     CameraSpots,
     CommandsExt,
@@ -17,13 +25,25 @@ impl Plugin for HoveringPlugin {
         app ////
             .register_type::<Hovering>()
             .init_resource::<Hovering>()
-            .add_systems(PreUpdate, hovering_raycast);
+            .add_systems(
+                Update,
+                hovering_raycast
+                    .in_set(HoverSystems)
+                    .after(look_around)
+                    .after(shake_camera),
+            );
     }
 }
 
+/// The systems that decide what the cursor is hovering over, which run before any `Interactive`.
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) struct HoverSystems;
+
 /// The entity that the cursor is currently hovering over, if any.
 ///
 /// This may or may not be an Interactive.
+///
+/// Updated in `Update`, once the Camera has moved for the frame.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Resource, Default, Reflect)]
 #[reflect(Resource)]
@@ -41,27 +61,34 @@ pub fn hovering_raycast(
     ctx: Res<RapierContext>,
     cursor: Res<Cursor>,
     at_spot: Res<CurrentSpot>,
+    examining: Option<Res<Examining>>,
 
     mut hovering: ResMut<Hovering>,
 
     windows: Query<&Window>,
-    cameras: Query<(&Camera, &GlobalTransform)>,
+    cameras: Query<(&Camera, &Transform)>,
     ignore: Query<&Ignores>,
+    parts: Query<(), With<ExaminePart>>,
 ) {
     if let Ok(window) = windows.get_single() {
-        if let Ok((camera, gtf)) = cameras.get_single() {
-            let ray = Ray3d::from_screenspace(cursor.position(), camera, gtf, window).unwrap();
+        if let Ok((camera, tf)) = cameras.get_single() {
+            // The Camera has no parent, and its GlobalTransform is only updated in `PostUpdate`,
+            // so the Transform is used to include this frame's look around and shake offsets
+            let gtf = GlobalTransform::from(*tf);
+            let ray = Ray3d::from_screenspace(cursor.position(), camera, &gtf, window).unwrap();
 
             let mut ignores = Vec::new();
 
-            if let Ok(ignored) = ignore.get(at_spot.get().entity()) {
-                ignores.extend(commands.named_any(&ignored.names));
-            }
-
-            if let Some(looking_at) = spots.for_spot(at_spot.get()) {
-                if let Ok(ignored) = ignore.get(looking_at) {
+            if let Some(spot) = at_spot.get() {
+                if let Ok(ignored) = ignore.get(spot.entity()) {
                     ignores.extend(commands.named_any(&ignored.names));
                 }
+
+                for looking_at in spots.interactives_for(spot) {
+                    if let Ok(ignored) = ignore.get(looking_at) {
+                        ignores.extend(commands.named_any(&ignored.names));
+                    }
+                }
             }
 
             hovering.entity = None;
@@ -71,7 +98,10 @@ pub fn hovering_raycast(
                 ray.direction(),
                 64.0,
                 true,
-                QueryFilter::new().predicate(&|entity| !ignores.contains(&entity)),
+                QueryFilter::new().predicate(&|entity| {
+                    // Only the examined model can be interacted with while examining an item
+                    !ignores.contains(&entity) && (examining.is_none() || parts.contains(entity))
+                }),
             ) {
                 hovering.entity = Some(entity);
             }
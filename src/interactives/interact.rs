@@ -10,28 +10,26 @@ use crate::{
     camera::{
         BackToSpot,
         BackToState,
+        CameraMoving,
+        CameraSettings,
         CameraSpots,
         CurrentSpot,
+        GoBack,
         NextSpot,
-        SkipAnimation,
+        SpotHistory,
     },
-    commands::CommandsExt,
+    examine::Examining,
     interactives::{
         hovering::Hovering,
-        Action,
+        ActionDispatcher,
         Interactive,
         ItemRef,
     },
-    inventory::{
-        DraggingItem,
-        Inventory,
-    },
-    state::WorldState,
-    textdisplay::{
-        Message,
-        TextDisplay,
-    },
+    inventory::DraggingItem,
+    scene::SceneManager,
+    transition::Transitioning,
     Cursor,
+    INVENTORY_HEIGHT,
     MAIN_CAMERA,
 };
 
@@ -115,6 +113,7 @@ impl Default for LookingAt {
 pub fn prepare_interaction<S: States>(
     mut commands: Commands,
     spots: CameraSpots,
+    scene: SceneManager,
 
     input: Res<Input<MouseButton>>,
     cursor: Res<Cursor>,
@@ -124,33 +123,75 @@ pub fn prepare_interaction<S: States>(
     dragging: Res<DraggingItem>,
     at_spot: ResMut<CurrentSpot>,
 
+    examining: Option<Res<Examining>>,
+    settings: Res<CameraSettings>,
+    moving: Res<CameraMoving>,
+    history: Res<SpotHistory>,
+    transitioning: Option<Res<Transitioning>>,
+
     back_spot: Query<&BackToSpot>,
     back_state: Query<&BackToState<S>>,
+    windows: Query<&Window>,
 ) {
+    let height = windows
+        .get_single()
+        .map_or(f32::MAX, |window| window.height());
+
     if interaction.ready() {
+        // Nothing can be interacted with until the scene and its assets are loaded
+        if !scene.ready()
+            || transitioning.is_some()
+            || (settings.block_interactions && moving.is_moving())
+        {
+            interaction.done();
+            return;
+        }
+
         if input.just_released(MouseButton::Left) {
-            if cursor.position().y > 100.0 {
-                interaction.begin();
+            if cursor.position().y < height - INVENTORY_HEIGHT {
+                if examining
+                    .as_ref()
+                    .is_some_and(|examining| examining.dragged())
+                {
+                    // The mouse was rotating the examined model
+                    interaction.done();
+                } else {
+                    interaction.begin();
+                }
             } else if !dragging.is_dragging() {
                 interaction.done();
 
-                let mut back = None;
-
-                if let Ok(spot) = back_spot.get(at_spot.get().entity()) {
-                    back = Some(spot);
-                } else if let Some(looking_at) = spots.for_spot(at_spot.get()) {
-                    if let Ok(spot) = back_spot.get(looking_at) {
-                        back = Some(spot);
+                if let Some(examining) = examining {
+                    if let Some(previous) = examining.previous() {
+                        commands.insert_resource(NextSpot(previous.to_owned()));
                     }
+
+                    commands.remove_resource::<Examining>();
+                    return;
                 }
 
+                let current = at_spot.get();
+
+                let back = current.and_then(|current| {
+                    back_spot.get(current.entity()).ok().or_else(|| {
+                        spots
+                            .interactives_for(current)
+                            .into_iter()
+                            .find_map(|looking_at| back_spot.get(looking_at).ok())
+                    })
+                });
+
+                let back_to_state = current.and_then(|spot| back_state.get(spot.entity()).ok());
+
+                // `BackToSpot` overrides `BackToState`, and both override the history
                 if let Some(spot) = back {
                     commands.insert_resource(NextSpot(spot.name.clone()));
+                } else if let Some(back) = back_to_state {
+                    commands.insert_resource(NextState(Some(back.state.clone())));
+                    commands.insert_resource(NextSpot(MAIN_CAMERA.to_owned()));
+                } else if !history.is_empty() {
+                    commands.insert_resource(GoBack);
                 } else {
-                    if let Ok(back) = back_state.get(at_spot.get().entity()) {
-                        commands.insert_resource(NextState(Some(back.state.clone())));
-                    }
-
                     commands.insert_resource(NextSpot(MAIN_CAMERA.to_owned()));
                 }
 
@@ -182,16 +223,12 @@ impl<'w, 's, I: Interactive + Component + 'static> Interactives<'w, 's, I> {
     }
 }
 
-#[allow(clippy::too_many_arguments)]
 #[allow(clippy::needless_pass_by_value)]
 pub fn interactive<I: Interactive + Component>(
-    mut commands: CommandsExt,
-    mut display: TextDisplay,
+    mut dispatcher: ActionDispatcher,
     spots: CameraSpots,
 
     dragging: Res<DraggingItem>,
-    mut inventory: ResMut<Inventory>,
-    mut state: ResMut<WorldState>,
     at_spot: ResMut<CurrentSpot>,
 
     mut interactives: Interactives<I>,
@@ -200,7 +237,9 @@ pub fn interactive<I: Interactive + Component>(
         let mut focused = true;
 
         if let Some(spot) = spots.for_interactive(entity) {
-            if at_spot.get().entity() != spot.entity() {
+            if at_spot.entity() != Some(spot.entity()) {
+                let commands = dispatcher.commands();
+
                 commands.insert_resource(NextSpot(spot.name().to_owned()));
                 commands.insert_resource(LookingAt(entity));
                 focused = false;
@@ -211,44 +250,23 @@ pub fn interactive<I: Interactive + Component>(
             let actions;
 
             if let Some(dragged) = &dragging.src {
-                let mut item = ItemRef::new(dragged);
+                let count = dispatcher.inventory.count(dragged);
+                let mut item = ItemRef::with_count(dragged, count);
 
-                actions = interactive.use_item(&mut state, &mut item);
+                actions = interactive.use_item(&mut dispatcher.state, &mut item);
 
                 if item.consumed() {
-                    inventory.items.remove(dragged);
+                    dispatcher.inventory.remove(dragged, item.consumed_count());
                 }
             } else {
-                actions = interactive.interact(&mut state);
+                actions = interactive.interact(&mut dispatcher.state);
             }
 
-            for action in actions {
-                match action {
-                    Action::AddItem(name) => {
-                        display.show(Message::ItemPickup(name.clone()));
-                        inventory.items.insert(name);
-                    }
-                    Action::Animation(name) => {
-                        commands.play_animation(&name);
-                    }
-                    Action::Audio(name) => {
-                        commands.play_audio(&name);
-                    }
-                    Action::Message(text) => display.show(text),
-                    Action::Transition(state) => {
-                        commands.insert_resource(NextState(Some(state)));
-                    }
-                    Action::Move(name) => commands.insert_resource(NextSpot(name)),
-                    Action::Jump(name) => {
-                        commands.insert_resource(NextSpot(name));
-                        commands.insert_resource(SkipAnimation);
-                    }
-                }
-            }
+            dispatcher.run(actions);
         }
     }
 
     for mut actions in &mut interactives.query {
-        actions.update(&mut commands, &mut state);
+        actions.update(&mut dispatcher.commands, &mut dispatcher.state);
     }
 }
@@ -1,5 +1,7 @@
 mod actions;
-mod hovering;
+mod container;
+mod dispatch;
+pub(crate) mod hovering;
 mod interact;
 mod plugin;
 mod ray;
@@ -11,6 +13,11 @@ pub use actions::{
     Action,
     Interactive,
 };
+pub use container::{
+    Container,
+    Containers,
+};
+pub use dispatch::ActionDispatcher;
 pub use hovering::Hovering;
 pub use interact::{
     interactive,
@@ -22,6 +29,7 @@ pub use plugin::InteractivesPlugin;
 pub use simple::{
     Description,
     MoveTo,
+    NoState,
     Portal,
     Prop,
     Simple,
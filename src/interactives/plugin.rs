@@ -6,7 +6,14 @@ use bevy::{
 };
 
 use crate::interactives::{
-    hovering::HoveringPlugin,
+    container::{
+        Container,
+        ContainerPlugin,
+    },
+    hovering::{
+        HoverSystems,
+        HoveringPlugin,
+    },
     interact::InteractionPlugin,
     interactive,
     simple::{
@@ -31,7 +38,7 @@ where
 {
     fn build(&self, app: &mut App) {
         app ////
-            .add_plugins((HoveringPlugin, InteractionPlugin))
+            .add_plugins((ContainerPlugin, HoveringPlugin, InteractionPlugin))
             ////
             //.register_type::<Simple<NoState>>()
             //.register_type::<Simple<S>>()
@@ -42,9 +49,11 @@ where
                 (
                     interactive::<Simple<NoState>>,
                     interactive::<Simple<S>>,
+                    interactive::<Container>,
                     interactive::<Prop>,
                     interactive::<Trigger>,
-                ),
+                )
+                    .after(HoverSystems),
             );
     }
 }
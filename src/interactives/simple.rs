@@ -13,9 +13,11 @@ use crate::{
     Ignores,
 };
 
+/// A placeholder state, for Actions that never change the current state.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, States, Reflect)]
 pub enum NoState {
+    /// The only variant.
     #[default]
     State,
 }
@@ -60,6 +62,13 @@ impl<State> Simple<State> {
         self.push(Action::Jump(name.to_owned()));
         self
     }
+
+    /// Add an `Action::Back` action to the `Simple`.
+    #[must_use]
+    pub fn back(mut self) -> Self {
+        self.push(Action::Back);
+        self
+    }
 }
 
 impl<State> From<Vec<Action<State>>> for Simple<State> {
@@ -113,6 +122,12 @@ impl Portal {
     pub fn build<State>(state: State) -> Simple<State> {
         Action::Transition(state).into()
     }
+
+    /// Returns a new instance of `Simple` that will change the current state when interacted with,
+    /// starting the new scene at the given spot.
+    pub fn build_to<State>(state: State, spot: &str) -> Simple<State> {
+        Action::TransitionTo(state, spot.to_owned()).into()
+    }
 }
 
 /// A preset `Interactive` that does nothing when interacted with.
@@ -2,36 +2,60 @@ use crate::Item;
 
 /// A reference to an item, passed into an Interactive.
 ///
-/// Allows the Interactive to optionally consume the item when used.
+/// Allows the Interactive to optionally consume some of the held units of the item when used.
 pub struct ItemRef<'a> {
     item: &'a Item,
-    consumed: bool,
+    count: u32,
+    consumed: u32,
 }
 
 impl<'a> ItemRef<'a> {
     /// Create a new `ItemRef`, given a borrowed item name.
     pub fn new(item: &'a Item) -> Self {
+        Self::with_count(item, 1)
+    }
+
+    /// Create a new `ItemRef`, given a borrowed item name and the number of units held.
+    pub fn with_count(item: &'a Item, count: u32) -> Self {
         Self {
             item,
-            consumed: false,
+            count,
+            consumed: 0,
         }
     }
 
-    /// Consume the item, remove it from the player's inventory.
+    /// Consume a single unit of the item, removing it from the player's inventory.
     pub fn consume(&mut self) {
-        self.consumed = true;
+        self.consume_n(1);
+    }
+
+    /// Consume `n` units of the item, removing them from the player's inventory.
+    ///
+    /// Consumes at most the number of units held, in total.
+    pub fn consume_n(&mut self, n: u32) {
+        self.consumed = (self.consumed + n).min(self.count);
     }
 
     /// Reverse the consumption of an item.
     pub fn restore(&mut self) {
-        self.consumed = false;
+        self.consumed = 0;
     }
 
     /// Returns whether the item has been consumed or not.
     pub fn consumed(&self) -> bool {
+        self.consumed > 0
+    }
+
+    /// Returns how many units of the item have been consumed.
+    pub fn consumed_count(&self) -> u32 {
         self.consumed
     }
 
+    /// Returns how many units of the item are held.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
     /// Returns the item name, as a str.
     pub fn as_str(&self) -> &str {
         self.item.as_str()
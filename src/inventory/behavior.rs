@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::{
+    interactives::{
+        Action,
+        ActionDispatcher,
+        ItemRef,
+        NoState,
+    },
+    inventory::{
+        Item,
+        ItemsCombined,
+    },
+    state::WorldState,
+};
+
+/// Trait that allows you to define behavior for an item that is used on another item in the inventory.
+///
+/// Behaviors are registered per item in the [`ItemBehaviors`] resource,
+/// and are consulted before [`Recipes`](crate::Recipes) when two items are combined.
+pub trait ItemBehavior: Send + Sync + 'static {
+    /// Method called whenever this item is used on another item, or another item is used on it.
+    ///
+    /// Returns a Vec of Actions defining what happens as a result.
+    /// Returning an empty Vec falls back to the registered `Recipes`.
+    fn use_on(
+        &mut self,
+        other: &mut ItemRef,
+        state: &mut ResMut<WorldState>,
+    ) -> Vec<Action<NoState>>;
+}
+
+impl<F> ItemBehavior for F
+where
+    F: FnMut(&mut ItemRef, &mut ResMut<WorldState>) -> Vec<Action<NoState>> + Send + Sync + 'static,
+{
+    fn use_on(
+        &mut self,
+        other: &mut ItemRef,
+        state: &mut ResMut<WorldState>,
+    ) -> Vec<Action<NoState>> {
+        self(other, state)
+    }
+}
+
+/// A resource that stores the [`ItemBehavior`] of each item that has one.
+#[derive(Resource, Default)]
+pub struct ItemBehaviors {
+    map: HashMap<Item, Box<dyn ItemBehavior>>,
+}
+
+impl ItemBehaviors {
+    /// Register the behavior of an item, replacing any existing behavior for it.
+    pub fn insert<B: ItemBehavior>(&mut self, id: &str, behavior: B) -> &mut Self {
+        self.map.insert(Item::new(id), Box::new(behavior));
+        self
+    }
+
+    /// Remove the behavior of an item.
+    pub fn remove(&mut self, item: &Item) {
+        self.map.remove(item);
+    }
+
+    /// Returns true if the item has a behavior.
+    pub fn contains(&self, item: &Item) -> bool {
+        self.map.contains_key(item)
+    }
+
+    /// Use `src` on `dst`, consulting the behavior of `src` first and then the behavior of `dst`.
+    ///
+    /// Returns true if a behavior returned any Actions, which have been run.
+    /// An [`ItemsCombined`] event is sent in that case, with the items added by the Actions as results.
+    pub(crate) fn use_on(
+        &mut self,
+        src: &Item,
+        dst: &Item,
+        dispatcher: &mut ActionDispatcher,
+        combined: &mut EventWriter<ItemsCombined>,
+    ) -> bool {
+        for (item, other) in [(src, dst), (dst, src)] {
+            if let Some(behavior) = self.map.get_mut(item) {
+                let count = dispatcher.inventory.count(other);
+                let mut other_ref = ItemRef::with_count(other, count);
+
+                let actions = behavior.use_on(&mut other_ref, &mut dispatcher.state);
+
+                if !actions.is_empty() {
+                    if other_ref.consumed() {
+                        dispatcher
+                            .inventory
+                            .remove(other, other_ref.consumed_count());
+                    }
+
+                    let results = actions
+                        .iter()
+                        .filter_map(|action| match action {
+                            Action::AddItem(item) | Action::AddItems(item, _) => Some(item.clone()),
+                            _ => None,
+                        })
+                        .collect();
+
+                    combined.send(ItemsCombined {
+                        items: vec![src.clone(), dst.clone()],
+                        results,
+                    });
+
+                    dispatcher.run(actions);
+
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+}
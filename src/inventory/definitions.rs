@@ -0,0 +1,515 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    marker::PhantomData,
+};
+
+use bevy::{
+    asset::{
+        io::Reader,
+        AssetLoader,
+        AsyncReadExt,
+        LoadContext,
+    },
+    prelude::*,
+    utils::BoxedFuture,
+};
+use serde::de::{
+    DeserializeOwned,
+    DeserializeSeed,
+    Error as _,
+    IgnoredAny,
+    MapAccess,
+    SeqAccess,
+    Visitor,
+};
+
+use crate::{
+    inventory::{
+        Item,
+        ItemInfo,
+        ItemRegistry,
+        Recipe,
+        Recipes,
+    },
+    textdisplay::Message,
+};
+
+pub struct ItemDefinitionsPlugin;
+
+impl Plugin for ItemDefinitionsPlugin {
+    fn build(&self, app: &mut App) {
+        app ////
+            .init_asset::<ItemDefinitions>()
+            .init_asset_loader::<ItemDefinitionsLoader>()
+            ////
+            .add_systems(PreUpdate, apply_item_definitions);
+    }
+}
+
+/// An asset containing item definitions and recipes, loaded from `.items.ron` or `.items.json` files.
+///
+/// Whenever one of these is loaded (or reloaded), its items and recipes replace the ones previously loaded
+/// from the same file in the [`ItemRegistry`] and [`Recipes`], so entries removed from the file are removed too.
+/// Keep the `Handle` returned by `AssetServer::load` alive, or the definitions will be unloaded.
+///
+/// Recipes that use the same items as another recipe are skipped, and a warning is logged for recipes that use items
+/// missing from a non-empty [`ItemRegistry`], since those may be defined in code instead.
+/// Both are logged with the file and line of the recipe.
+///
+/// ```ron
+/// (
+///     items: [
+///         ("Batteries", (name: "Batteries", description: "A pair of AA batteries.")),
+///         ("Flashlight", (name: "Flashlight")),
+///         ("FlashlightEmpty", (name: "Flashlight", description: "It needs batteries.")),
+///     ],
+///     recipes: [
+///         (
+///             ingredients: ["FlashlightEmpty", "Batteries"],
+///             outputs: [(item: "Flashlight", count: 1)],
+///             actions: [Audio("click"), Set("flashlight", "true")],
+///         ),
+///     ],
+///     failures: [
+///         ("Flashlight", "Batteries", Text("It already has batteries.")),
+///     ],
+/// )
+/// ```
+#[derive(Asset, TypePath, Debug, Default, serde::Deserialize)]
+pub struct ItemDefinitions {
+    /// The item ids and their metadata.
+    #[serde(default)]
+    pub items: Vec<(String, ItemInfo)>,
+
+    /// The recipes.
+    #[serde(default)]
+    pub recipes: Vec<Recipe>,
+
+    /// Messages shown when two items that do not combine are combined.
+    #[serde(default)]
+    pub failures: Vec<(Item, Item, Message)>,
+
+    #[serde(skip)]
+    path: String,
+
+    #[serde(skip)]
+    source: Vec<u8>,
+
+    #[serde(skip)]
+    json: bool,
+}
+
+impl ItemDefinitions {
+    /// Returns the file and line of an element of the `recipes` or `failures` list, for error messages.
+    fn location(&self, list: &'static str, index: usize) -> String {
+        let seed = ElementLine { list, index };
+
+        // The file was parsed once already, so the only error is the one stopping right before the element
+        let line = if self.json {
+            let mut de = serde_json::Deserializer::from_slice(&self.source);
+            seed.deserialize(&mut de).err().map(|err| err.line())
+        } else {
+            ron::Deserializer::from_bytes(&self.source)
+                .ok()
+                .and_then(|mut de| {
+                    let err = seed.deserialize(&mut de).err()?;
+                    Some(de.span_error(err).position.line)
+                })
+        };
+
+        match line {
+            Some(line) => format!("{}:{line}", self.path),
+            None => format!("{}: {list} {index}", self.path),
+        }
+    }
+}
+
+/// Parses item definitions up to an element of one of their lists, and fails there.
+///
+/// The error reported by the parser then holds the position of the element.
+struct ElementLine {
+    list: &'static str,
+    index: usize,
+}
+
+impl<'de> DeserializeSeed<'de> for ElementLine {
+    type Value = ();
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_struct("ItemDefinitions", &["items", "recipes", "failures"], self)
+    }
+}
+
+impl<'de> Visitor<'de> for ElementLine {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("item definitions")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        while let Some(field) = map.next_key::<Field>()? {
+            match (field, self.list) {
+                (Field::Recipes, "recipes") => {
+                    return map.next_value_seed(StopAt::<Recipe>::new(self.index));
+                }
+                (Field::Failures, "failures") => {
+                    return map.next_value_seed(StopAt::<(Item, Item, Message)>::new(self.index));
+                }
+                (Field::Items, _) => {
+                    map.next_value::<Vec<(String, ItemInfo)>>()?;
+                }
+                (Field::Recipes, _) => {
+                    map.next_value::<Vec<Recipe>>()?;
+                }
+                (Field::Failures, _) => {
+                    map.next_value::<Vec<(Item, Item, Message)>>()?;
+                }
+                (Field::Other, _) => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The fields of [`ItemDefinitions`], parsed as identifiers like the derived implementation does.
+#[derive(serde::Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Items,
+    Recipes,
+    Failures,
+    #[serde(other)]
+    Other,
+}
+
+/// Parses the elements of a list before `index`, and fails on the element at `index`.
+struct StopAt<T> {
+    index: usize,
+    element: PhantomData<T>,
+}
+
+impl<T> StopAt<T> {
+    fn new(index: usize) -> Self {
+        Self {
+            index,
+            element: PhantomData,
+        }
+    }
+}
+
+impl<'de, T: DeserializeOwned> DeserializeSeed<'de> for StopAt<T> {
+    type Value = ();
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, T: DeserializeOwned> Visitor<'de> for StopAt<T> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a list")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        for _ in 0..self.index {
+            if seq.next_element::<T>()?.is_none() {
+                return Ok(());
+            }
+        }
+
+        seq.next_element_seed(Stop).map(|_| ())
+    }
+}
+
+/// Fails as soon as the parser reaches it.
+struct Stop;
+
+impl<'de> DeserializeSeed<'de> for Stop {
+    type Value = ();
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, _deserializer: D) -> Result<(), D::Error> {
+        Err(D::Error::custom("found the element"))
+    }
+}
+
+#[derive(Default)]
+struct ItemDefinitionsLoader;
+
+#[derive(Debug)]
+enum ItemDefinitionsError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+    Json(serde_json::Error),
+}
+
+impl std::fmt::Display for ItemDefinitionsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "could not read item definitions: {err}"),
+            Self::Ron(err) => write!(f, "could not parse item definitions: {err}"),
+            Self::Json(err) => write!(f, "could not parse item definitions: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ItemDefinitionsError {}
+
+impl AssetLoader for ItemDefinitionsLoader {
+    type Asset = ItemDefinitions;
+    type Settings = ();
+    type Error = ItemDefinitionsError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+
+            reader
+                .read_to_end(&mut bytes)
+                .await
+                .map_err(ItemDefinitionsError::Io)?;
+
+            let path = load_context.path();
+
+            let json = path.extension().is_some_and(|ext| ext == "json");
+
+            let mut definitions: ItemDefinitions = if json {
+                serde_json::from_slice(&bytes).map_err(ItemDefinitionsError::Json)?
+            } else {
+                ron::de::from_bytes(&bytes).map_err(ItemDefinitionsError::Ron)?
+            };
+
+            definitions.path = path.display().to_string();
+            definitions.source = bytes;
+            definitions.json = json;
+
+            Ok(definitions)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["items.ron", "items.json"]
+    }
+}
+
+/// The file and the items loaded from an [`ItemDefinitions`] asset.
+struct LoadedDefinitions {
+    path: String,
+    items: Vec<Item>,
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn apply_item_definitions(
+    mut events: EventReader<AssetEvent<ItemDefinitions>>,
+    mut loaded: Local<HashMap<AssetId<ItemDefinitions>, LoadedDefinitions>>,
+    assets: Res<Assets<ItemDefinitions>>,
+    mut registry: ResMut<ItemRegistry>,
+    mut recipes: ResMut<Recipes>,
+) {
+    for event in events.read() {
+        match event {
+            AssetEvent::Added { id } | AssetEvent::Modified { id } => {
+                if let Some(definitions) = assets.get(*id) {
+                    let items = definitions
+                        .items
+                        .iter()
+                        .map(|(name, _)| Item::new(name))
+                        .collect::<Vec<_>>();
+
+                    // Items deleted from the file are unregistered, the others keep their order
+                    if let Some(previous) = loaded.get(id) {
+                        for item in previous.items.iter().filter(|item| !items.contains(item)) {
+                            registry.remove(item);
+                        }
+                    }
+
+                    for (name, info) in &definitions.items {
+                        registry.insert(name, info.clone());
+                    }
+
+                    recipes.unload(&definitions.path);
+
+                    let (valid, failures) = validate(definitions, &registry, &recipes);
+
+                    recipes.load(&definitions.path, valid, failures);
+
+                    loaded.insert(*id, LoadedDefinitions {
+                        path: definitions.path.clone(),
+                        items,
+                    });
+                }
+            }
+            AssetEvent::Removed { id } => {
+                if let Some(previous) = loaded.remove(id) {
+                    recipes.unload(&previous.path);
+
+                    for item in &previous.items {
+                        registry.remove(item);
+                    }
+                }
+            }
+            AssetEvent::LoadedWithDependencies { .. } => (),
+        }
+    }
+}
+
+/// Returns the recipes and failure messages that are valid, logging an error for every other one.
+///
+/// Unknown items only log a warning, like adding them to the `Inventory` does.
+fn validate(
+    definitions: &ItemDefinitions,
+    registry: &ItemRegistry,
+    recipes: &Recipes,
+) -> (Vec<Recipe>, Vec<(Item, Item, Message)>) {
+    let mut valid = Vec::<Recipe>::new();
+    let mut failures = Vec::new();
+
+    let unknown = |items: &[&Item]| {
+        items
+            .iter()
+            .filter(|item| !registry.is_empty() && !registry.contains(item))
+            .map(|item| format!("{:?}", item.as_str()))
+            .collect::<Vec<_>>()
+    };
+
+    for (index, recipe) in definitions.recipes.iter().enumerate() {
+        let location = || definitions.location("recipes", index);
+
+        let items = recipe
+            .inputs()
+            .chain(recipe.outputs.iter().map(|output| &output.item))
+            .collect::<Vec<_>>();
+
+        let unknown = unknown(&items);
+
+        if !unknown.is_empty() {
+            warn!(
+                "{} uses items missing from the ItemRegistry: {}",
+                location(),
+                unknown.join(", ")
+            );
+        }
+
+        let inputs = recipe.inputs().cloned().collect::<Vec<_>>();
+
+        if recipes.find(&inputs).is_some() || valid.iter().any(|other| other.matches(&inputs)) {
+            error!("{} combines the same items as another recipe", location());
+            continue;
+        }
+
+        valid.push(recipe.clone());
+    }
+
+    for (index, (a, b, message)) in definitions.failures.iter().enumerate() {
+        let location = || definitions.location("failures", index);
+
+        let unknown = unknown(&[a, b]);
+
+        if !unknown.is_empty() {
+            warn!(
+                "{} uses items missing from the ItemRegistry: {}",
+                location(),
+                unknown.join(", ")
+            );
+        }
+
+        let duplicate = failures
+            .iter()
+            .any(|(x, y, _)| (x == a && y == b) || (x == b && y == a));
+
+        if recipes.failure(a, b).is_some() || duplicate {
+            error!(
+                "{} uses the same items as another failure message",
+                location()
+            );
+            continue;
+        }
+
+        failures.push((a.clone(), b.clone(), message.clone()));
+    }
+
+    (valid, failures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(path: &str, source: &str) -> ItemDefinitions {
+        let json = source.starts_with('{');
+
+        let mut definitions: ItemDefinitions = if json {
+            serde_json::from_str(source).unwrap()
+        } else {
+            ron::de::from_str(source).unwrap()
+        };
+
+        definitions.path = path.to_owned();
+        definitions.source = source.as_bytes().to_vec();
+        definitions.json = json;
+        definitions
+    }
+
+    #[test]
+    fn locates_ron_entries_by_line() {
+        let definitions = parse(
+            "puzzle.items.ron",
+            r#"(
+    // The items come first
+    items: [
+        ("Batteries", (name: "Batteries")),
+    ],
+    recipes: [
+        (
+            ingredients: ["FlashlightEmpty", "Batteries"],
+            outputs: [(item: "Flashlight", count: 1)],
+            actions: [Audio("click")],
+        ),
+        (ingredients: ["Rope", "Hook"]),
+    ],
+    failures: [("Flashlight", "Batteries", Text("It already has batteries."))],
+)"#,
+        );
+
+        assert_eq!(definitions.location("recipes", 0), "puzzle.items.ron:7");
+        assert_eq!(definitions.location("recipes", 1), "puzzle.items.ron:12");
+        assert_eq!(definitions.location("failures", 0), "puzzle.items.ron:14");
+    }
+
+    #[test]
+    fn locates_json_entries_by_line() {
+        let definitions = parse(
+            "puzzle.items.json",
+            r#"{
+    "recipes": [
+        { "ingredients": ["Rope", "Hook"] },
+        { "ingredients": ["Key", "Lock"] }
+    ]
+}"#,
+        );
+
+        assert_eq!(definitions.location("recipes", 0), "puzzle.items.json:3");
+        assert_eq!(definitions.location("recipes", 1), "puzzle.items.json:4");
+    }
+
+    #[test]
+    fn falls_back_to_the_index() {
+        let definitions = parse("puzzle.items.ron", "(recipes: [])");
+
+        assert_eq!(
+            definitions.location("recipes", 3),
+            "puzzle.items.ron: recipes 3"
+        );
+    }
+}
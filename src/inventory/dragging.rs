@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+use crate::inventory::Item;
+
+/// A resource that stores which items are being dragged, if any.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource)]
+pub struct DraggingItem {
+    /// Source item name
+    pub src: Option<Item>,
+
+    /// Destination item name
+    pub dst: Option<Item>,
+}
+
+impl DraggingItem {
+    /// Returns true if either the source or the destination is Some.
+    pub fn is_dragging(&self) -> bool {
+        self.src.is_some() || self.dst.is_some()
+    }
+}
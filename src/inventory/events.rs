@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::inventory::{
+    Inventory,
+    Item,
+};
+
+pub struct InventoryEventsPlugin;
+
+impl Plugin for InventoryEventsPlugin {
+    fn build(&self, app: &mut App) {
+        app ////
+            .add_event::<ItemAdded>()
+            .add_event::<ItemRemoved>()
+            .add_event::<ItemsCombined>()
+            ////
+            .add_systems(PostUpdate, send_inventory_events);
+    }
+}
+
+/// An event that fires whenever units of an item are added to the [`Inventory`].
+#[derive(Event, Clone, Debug)]
+pub struct ItemAdded {
+    /// The item that was added.
+    pub item: Item,
+
+    /// How many units were added.
+    pub count: u32,
+}
+
+/// An event that fires whenever units of an item are removed from the [`Inventory`].
+#[derive(Event, Clone, Debug)]
+pub struct ItemRemoved {
+    /// The item that was removed.
+    pub item: Item,
+
+    /// How many units were removed.
+    pub count: u32,
+}
+
+/// An event that fires whenever items are successfully combined with a recipe or an [`ItemBehavior`](crate::ItemBehavior).
+///
+/// The matching [`ItemAdded`] and [`ItemRemoved`] events are sent as well.
+#[derive(Event, Clone, Debug)]
+pub struct ItemsCombined {
+    /// The items that were combined, including tools.
+    pub items: Vec<Item>,
+
+    /// The items that were produced.
+    ///
+    /// For an `ItemBehavior`, these are the items added by its `AddItem` and `AddItems` Actions.
+    pub results: Vec<Item>,
+}
+
+/// Compares the `Inventory` with the one from the last time it changed, and sends events for the difference.
+///
+/// Units added and removed again in the same frame cancel out.
+#[allow(clippy::needless_pass_by_value)]
+fn send_inventory_events(
+    inventory: Res<Inventory>,
+    mut held: Local<HashMap<Item, u32>>,
+    mut added: EventWriter<ItemAdded>,
+    mut removed: EventWriter<ItemRemoved>,
+) {
+    if !inventory.is_changed() {
+        return;
+    }
+
+    for stack in inventory.stacks() {
+        let before = held.get(&stack.item).copied().unwrap_or_default();
+
+        if stack.count > before {
+            added.send(ItemAdded {
+                item: stack.item.clone(),
+                count: stack.count - before,
+            });
+        }
+    }
+
+    for (item, before) in &*held {
+        let count = inventory.count(item);
+
+        if count < *before {
+            removed.send(ItemRemoved {
+                item: item.clone(),
+                count: before - count,
+            });
+        }
+    }
+
+    *held = inventory
+        .stacks()
+        .map(|stack| (stack.item.clone(), stack.count))
+        .collect();
+}
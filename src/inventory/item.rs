@@ -0,0 +1,73 @@
+use bevy::prelude::*;
+
+/// An item.
+///
+/// Besides its serialized form, `(name: "Key")`, an item can be deserialized from a plain string, like `"Key"`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Reflect)]
+pub struct Item {
+    name: String,
+}
+
+impl Item {
+    /// Returns a new Item with the given name.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+        }
+    }
+
+    /// The name of the Item.
+    pub fn as_str(&self) -> &str {
+        &self.name
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Item {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Name(String),
+            Item { name: String },
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Name(name) | Repr::Item { name } => Ok(Self { name }),
+        }
+    }
+}
+
+impl From<&str> for Item {
+    fn from(name: &str) -> Self {
+        Item::new(name)
+    }
+}
+
+impl From<String> for Item {
+    fn from(name: String) -> Self {
+        Item::new(&name)
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_struct_and_string() {
+        let item = Item::new("Key");
+
+        assert_eq!(ron::to_string(&item).unwrap(), "(name:\"Key\")");
+        assert_eq!(ron::from_str::<Item>("(name: \"Key\")").unwrap(), item);
+        assert_eq!(ron::from_str::<Item>("\"Key\"").unwrap(), item);
+
+        assert_eq!(serde_json::to_string(&item).unwrap(), "{\"name\":\"Key\"}");
+        assert_eq!(
+            serde_json::from_str::<Item>("{\"name\":\"Key\"}").unwrap(),
+            item
+        );
+        assert_eq!(serde_json::from_str::<Item>("\"Key\"").unwrap(), item);
+    }
+}
@@ -0,0 +1,38 @@
+mod behavior;
+#[cfg(feature = "serde")]
+mod definitions;
+mod dragging;
+mod events;
+mod item;
+mod plugin;
+pub(crate) mod recipes;
+mod registry;
+mod storage;
+
+pub use behavior::{
+    ItemBehavior,
+    ItemBehaviors,
+};
+#[cfg(feature = "serde")]
+pub use definitions::ItemDefinitions;
+pub use dragging::DraggingItem;
+pub use events::{
+    ItemAdded,
+    ItemRemoved,
+    ItemsCombined,
+};
+pub use item::Item;
+pub use plugin::InventoryPlugin;
+pub use recipes::{
+    PendingCombination,
+    Recipe,
+    Recipes,
+};
+pub use registry::{
+    ItemInfo,
+    ItemRegistry,
+};
+pub use storage::{
+    Inventory,
+    ItemStack,
+};
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+#[cfg(feature = "serde")]
+use crate::inventory::definitions::ItemDefinitionsPlugin;
+use crate::inventory::{
+    events::InventoryEventsPlugin,
+    recipes::RecipesPlugin,
+    registry::ItemRegistryPlugin,
+    DraggingItem,
+    Inventory,
+    Item,
+    ItemStack,
+};
+
+pub struct InventoryPlugin;
+
+impl Plugin for InventoryPlugin {
+    fn build(&self, app: &mut App) {
+        app ////
+            .register_type::<Inventory>()
+            .register_type::<ItemStack>()
+            .register_type::<Option<ItemStack>>()
+            .register_type::<Vec<Option<ItemStack>>>()
+            .register_type::<Option<usize>>()
+            .register_type::<Item>()
+            .register_type::<DraggingItem>()
+            .register_type::<Option<Item>>()
+            ////
+            .init_resource::<DraggingItem>()
+            .init_resource::<Inventory>()
+            ////
+            .add_plugins((InventoryEventsPlugin, ItemRegistryPlugin, RecipesPlugin));
+
+        #[cfg(feature = "serde")]
+        app ////
+            .add_plugins(ItemDefinitionsPlugin);
+    }
+}
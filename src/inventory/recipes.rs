@@ -0,0 +1,454 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::{
+    interactives::{
+        Action,
+        ActionDispatcher,
+        NoState,
+    },
+    inventory::{
+        DraggingItem,
+        Item,
+        ItemBehaviors,
+        ItemStack,
+        ItemsCombined,
+    },
+    textdisplay::Message,
+};
+
+pub struct RecipesPlugin;
+
+impl Plugin for RecipesPlugin {
+    fn build(&self, app: &mut App) {
+        app ////
+            .register_type::<Recipes>()
+            .register_type::<Recipe>()
+            .register_type::<Vec<Recipe>>()
+            .register_type::<Vec<Item>>()
+            .register_type::<Vec<ItemStack>>()
+            .register_type::<PendingCombination>()
+            ////
+            .init_resource::<Recipes>()
+            .init_resource::<PendingCombination>()
+            .init_resource::<ItemBehaviors>()
+            ////
+            .add_systems(Update, handle_combine);
+    }
+}
+
+/// A single item combination.
+///
+/// Ingredients are consumed when the recipe is used, tools are required but kept.
+/// Any Actions are run after the outputs have been added to the inventory.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Debug, Default, Reflect)]
+pub struct Recipe {
+    /// Items that are consumed by the recipe, one unit each.
+    pub ingredients: Vec<Item>,
+
+    /// Items that are required by the recipe, but kept.
+    pub tools: Vec<Item>,
+
+    /// Items that are produced by the recipe.
+    pub outputs: Vec<ItemStack>,
+
+    /// Actions that are run when the recipe is used.
+    #[reflect(ignore)]
+    pub actions: Vec<Action<NoState>>,
+}
+
+impl Recipe {
+    /// Returns a new, empty `Recipe`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an ingredient to the recipe.
+    #[must_use]
+    pub fn ingredient(mut self, item: &str) -> Self {
+        self.ingredients.push(item.into());
+        self
+    }
+
+    /// Add a tool to the recipe.
+    #[must_use]
+    pub fn tool(mut self, item: &str) -> Self {
+        self.tools.push(item.into());
+        self
+    }
+
+    /// Add a single unit of an output item to the recipe.
+    #[must_use]
+    pub fn output(self, item: &str) -> Self {
+        self.output_n(item, 1)
+    }
+
+    /// Add a number of units of an output item to the recipe.
+    #[must_use]
+    pub fn output_n(mut self, item: &str, count: u32) -> Self {
+        self.outputs.push(ItemStack {
+            item: item.into(),
+            count,
+        });
+        self
+    }
+
+    /// Add an Action that is run when the recipe is used.
+    #[must_use]
+    pub fn action(mut self, action: Action<NoState>) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// Add an `Action::Audio` action to the recipe.
+    #[must_use]
+    pub fn audio(self, name: &str) -> Self {
+        self.action(Action::Audio(name.to_owned()))
+    }
+
+    /// Add an `Action::Message` action to the recipe.
+    ///
+    /// The message is shown instead of the default `Message::ItemCombine`.
+    #[must_use]
+    pub fn message(self, message: Message) -> Self {
+        self.action(Action::Message(message))
+    }
+
+    /// Returns true if the recipe sends its own message when used.
+    pub fn has_message(&self) -> bool {
+        self.actions
+            .iter()
+            .any(|action| matches!(action, Action::Message(_)))
+    }
+
+    /// Iterate over the ingredients and tools of the recipe.
+    pub fn inputs(&self) -> impl Iterator<Item = &Item> {
+        self.ingredients.iter().chain(&self.tools)
+    }
+
+    /// Returns true if the given items are exactly the inputs of the recipe, in any order.
+    pub fn matches(&self, items: &[Item]) -> bool {
+        items.len() == self.inputs().count() && self.accepts(items)
+    }
+
+    /// Returns true if all of the given items are inputs of the recipe, in any order.
+    pub fn accepts(&self, items: &[Item]) -> bool {
+        let mut remaining = self.inputs().collect::<Vec<_>>();
+
+        for item in items {
+            if let Some(index) = remaining.iter().position(|input| *input == item) {
+                remaining.swap_remove(index);
+            } else {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// A resource that stores all registered item combinations.
+///
+/// It can also store messages for pairs of items that do not combine,
+/// which are shown instead of the generic `Message::InvalidItemCombination`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource)]
+pub struct Recipes {
+    registered: Vec<Recipe>,
+
+    #[reflect(ignore)]
+    failures: Vec<(Item, Item, Message)>,
+
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    loaded: HashMap<String, LoadedFile>,
+}
+
+/// Recipes and failure messages loaded from a single asset file.
+#[derive(Debug, Default)]
+struct LoadedFile {
+    recipes: Vec<Recipe>,
+    failures: Vec<(Item, Item, Message)>,
+}
+
+impl Recipes {
+    /// Insert a new recipe that combines two items into one.
+    /// Order is ignored (`(a, b) == (b, a)`).
+    ///
+    /// Replaces any registered recipe for the same pair of items.
+    pub fn insert(&mut self, a: &str, b: &str, result: &str) {
+        self.add(Recipe::new().ingredient(a).ingredient(b).output(result));
+    }
+
+    /// Add a recipe.
+    ///
+    /// Replaces any registered recipe with the same ingredients and tools.
+    pub fn add(&mut self, recipe: Recipe) -> &mut Self {
+        let inputs = recipe.inputs().cloned().collect::<Vec<_>>();
+
+        if let Some(existing) = self
+            .registered
+            .iter_mut()
+            .find(|existing| existing.matches(&inputs))
+        {
+            *existing = recipe;
+        } else {
+            self.registered.push(recipe);
+        }
+
+        self
+    }
+
+    /// Insert a message that is shown when two items are combined, but do not make anything.
+    /// Order is ignored (`(a, b) == (b, a)`).
+    pub fn insert_failure(&mut self, a: &str, b: &str, message: Message) -> &mut Self {
+        self.failures.push((a.into(), b.into(), message));
+        self
+    }
+
+    /// Returns the failure message for a pair of items, if any.
+    pub fn failure(&self, a: &Item, b: &Item) -> Option<&Message> {
+        self.failures()
+            .find(|(x, y, _)| (x == a && y == b) || (x == b && y == a))
+            .map(|(_, _, message)| message)
+    }
+
+    /// Given a source and a destination, return the matching combination result, if any.
+    pub fn get(&self, src: &Item, dst: &Item) -> Option<&Item> {
+        self.find(&[src.clone(), dst.clone()])
+            .and_then(|recipe| recipe.outputs.first())
+            .map(|output| &output.item)
+    }
+
+    /// Returns the recipe that uses exactly the given items, if any.
+    pub fn find(&self, items: &[Item]) -> Option<&Recipe> {
+        self.iter().find(|recipe| recipe.matches(items))
+    }
+
+    /// Returns true if the given items are part of a recipe that needs more items.
+    pub fn is_partial(&self, items: &[Item]) -> bool {
+        self.iter()
+            .any(|recipe| recipe.accepts(items) && !recipe.matches(items))
+    }
+
+    /// Iterate over all registered recipes, including those loaded from asset files.
+    pub fn iter(&self) -> impl Iterator<Item = &Recipe> {
+        self.registered
+            .iter()
+            .chain(self.loaded.values().flat_map(|loaded| &loaded.recipes))
+    }
+
+    fn failures(&self) -> impl Iterator<Item = &(Item, Item, Message)> {
+        self.failures
+            .iter()
+            .chain(self.loaded.values().flat_map(|loaded| &loaded.failures))
+    }
+
+    /// Replace the recipes and failure messages loaded from the asset file at `path`.
+    #[cfg(feature = "serde")]
+    pub(crate) fn load(
+        &mut self,
+        path: &str,
+        recipes: Vec<Recipe>,
+        failures: Vec<(Item, Item, Message)>,
+    ) {
+        self.loaded
+            .insert(path.to_owned(), LoadedFile { recipes, failures });
+    }
+
+    /// Remove the recipes and failure messages loaded from the asset file at `path`.
+    #[cfg(feature = "serde")]
+    pub(crate) fn unload(&mut self, path: &str) {
+        self.loaded.remove(path);
+    }
+}
+
+/// A resource that stores the items of a combination that is still missing ingredients.
+///
+/// When two items are combined that are only part of a larger recipe, they are kept here.
+/// Dragging another item onto one of them adds it to the combination.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct PendingCombination {
+    items: Vec<Item>,
+}
+
+impl PendingCombination {
+    /// Returns the items that have been combined so far.
+    pub fn items(&self) -> &[Item] {
+        &self.items
+    }
+
+    /// Returns true if no combination is in progress.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Abandon the combination in progress.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub(crate) fn handle_combine(
+    input: Res<Input<MouseButton>>,
+    mut dispatcher: ActionDispatcher,
+    mut combined: EventWriter<ItemsCombined>,
+    mut pending: ResMut<PendingCombination>,
+    mut behaviors: ResMut<ItemBehaviors>,
+    recipes: Res<Recipes>,
+    dragging: Res<DraggingItem>,
+) {
+    let inventory = &dispatcher.inventory;
+
+    if !pending.items.iter().all(|item| inventory.contains(item)) {
+        pending.clear();
+    }
+
+    if input.just_released(MouseButton::Left) {
+        if let (Some(src), Some(dst)) = (&dragging.src, &dragging.dst) {
+            if behaviors.use_on(src, dst, &mut dispatcher, &mut combined) {
+                pending.clear();
+                return;
+            }
+
+            let mut items = pending.items.clone();
+
+            if items.contains(src) && !items.contains(dst) {
+                items.push(dst.clone());
+            } else if items.contains(dst) && !items.contains(src) {
+                items.push(src.clone());
+            } else {
+                items = vec![src.clone(), dst.clone()];
+            }
+
+            if let Some(recipe) = recipes.find(&items) {
+                pending.clear();
+
+                // Make sure every output fits before consuming anything
+                let mut after = dispatcher.inventory.clone();
+
+                for item in &recipe.ingredients {
+                    after.remove(item, 1);
+                }
+
+                let fits = recipe
+                    .outputs
+                    .iter()
+                    .all(|output| after.add(output.item.clone(), output.count));
+
+                if !fits {
+                    dispatcher.show(Message::InventoryFull);
+                    return;
+                }
+
+                *dispatcher.inventory() = after;
+
+                let results = recipe
+                    .outputs
+                    .iter()
+                    .map(|output| output.item.clone())
+                    .collect::<Vec<_>>();
+
+                combined.send(ItemsCombined {
+                    items: items.clone(),
+                    results: results.clone(),
+                });
+
+                if !recipe.has_message() {
+                    if let ([src, dst], [result]) = (&items[..], &results[..]) {
+                        let src = src.clone();
+                        let dst = dst.clone();
+                        let result = result.clone();
+
+                        dispatcher.show(Message::ItemCombine { src, dst, result });
+                    } else {
+                        dispatcher.show(Message::ItemCombineMany { items, results });
+                    }
+                }
+
+                dispatcher.run(recipe.actions.clone());
+            } else if recipes.is_partial(&items) {
+                pending.items.clone_from(&items);
+
+                dispatcher.show(Message::PartialItemCombination(items));
+            } else {
+                pending.clear();
+
+                let message = recipes
+                    .failure(src, dst)
+                    .cloned()
+                    .unwrap_or(Message::InvalidItemCombination);
+
+                dispatcher.show(message);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items(names: &[&str]) -> Vec<Item> {
+        names.iter().map(|name| Item::new(name)).collect()
+    }
+
+    #[test]
+    fn matches_inputs_in_any_order() {
+        let recipe = Recipe::new()
+            .ingredient("rope")
+            .ingredient("hook")
+            .tool("knife")
+            .output("grapple");
+
+        assert!(recipe.matches(&items(&["rope", "hook", "knife"])));
+        assert!(recipe.matches(&items(&["knife", "hook", "rope"])));
+        assert!(!recipe.matches(&items(&["rope", "hook"])));
+        assert!(!recipe.matches(&items(&["rope", "hook", "knife", "stick"])));
+    }
+
+    #[test]
+    fn accepts_partial_inputs() {
+        let recipe = Recipe::new()
+            .ingredient("rope")
+            .ingredient("hook")
+            .tool("knife");
+
+        assert!(recipe.accepts(&items(&["hook", "knife"])));
+        assert!(!recipe.accepts(&items(&["hook", "stick"])));
+        assert!(!recipe.accepts(&items(&["hook", "hook"])));
+    }
+
+    #[test]
+    fn find_is_order_independent() {
+        let mut recipes = Recipes::default();
+        recipes.insert("a", "b", "c");
+
+        let (a, b) = (Item::new("a"), Item::new("b"));
+
+        assert_eq!(recipes.get(&a, &b), Some(&Item::new("c")));
+        assert_eq!(recipes.get(&b, &a), Some(&Item::new("c")));
+        assert!(recipes.find(&items(&["a"])).is_none());
+        assert!(recipes.is_partial(&items(&["a"])));
+    }
+
+    #[test]
+    fn insert_replaces_same_pair() {
+        let mut recipes = Recipes::default();
+        recipes.insert("a", "b", "c");
+        recipes.insert("b", "a", "d");
+
+        assert_eq!(recipes.iter().count(), 1);
+        assert_eq!(
+            recipes.get(&Item::new("a"), &Item::new("b")),
+            Some(&Item::new("d"))
+        );
+    }
+}
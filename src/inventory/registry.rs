@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::inventory::Item;
+
+pub struct ItemRegistryPlugin;
+
+impl Plugin for ItemRegistryPlugin {
+    fn build(&self, app: &mut App) {
+        app ////
+            .register_type::<ItemRegistry>()
+            .register_type::<ItemInfo>()
+            .register_type::<Vec<Item>>()
+            .register_type::<HashMap<Item, ItemInfo>>()
+            ////
+            .init_resource::<ItemRegistry>();
+    }
+}
+
+/// Metadata describing an [`Item`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[derive(Clone, Debug, Default, Reflect)]
+pub struct ItemInfo {
+    /// The name shown to the player.
+    pub name: String,
+
+    /// A short description, suitable for tooltips.
+    pub description: String,
+
+    /// Path to an icon image that can be loaded by the `AssetServer`.
+    pub icon: Option<String>,
+
+    /// Text shown when the player takes a closer look at the item.
+    pub examine: Option<String>,
+
+    /// Path to a glTF scene that is shown when the player takes a closer look at the item,
+    /// for example `"items/flashlight.glb#Scene0"`.
+    pub model: Option<String>,
+
+    /// Arbitrary tags, useful for grouping and sorting items.
+    pub tags: Vec<String>,
+}
+
+impl ItemInfo {
+    /// Returns a new `ItemInfo` with the given display name.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            ..default()
+        }
+    }
+
+    /// Set the description of the item.
+    #[must_use]
+    pub fn description(mut self, description: &str) -> Self {
+        description.clone_into(&mut self.description);
+        self
+    }
+
+    /// Set the path to the icon of the item.
+    #[must_use]
+    pub fn icon(mut self, path: &str) -> Self {
+        self.icon = Some(path.to_owned());
+        self
+    }
+
+    /// Set the examine text of the item.
+    #[must_use]
+    pub fn examine(mut self, text: &str) -> Self {
+        self.examine = Some(text.to_owned());
+        self
+    }
+
+    /// Set the path to the model of the item.
+    #[must_use]
+    pub fn model(mut self, path: &str) -> Self {
+        self.model = Some(path.to_owned());
+        self
+    }
+
+    /// Add a tag to the item.
+    #[must_use]
+    pub fn tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.to_owned());
+        self
+    }
+
+    /// Returns true if the item has the given tag.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+}
+
+/// A resource that maps items to their [`ItemInfo`].
+///
+/// Items can be registered in code with [`ItemRegistry::insert`],
+/// or loaded from an `.items.ron` or `.items.json` file with the `serde` feature (see [`ItemDefinitions`](crate::ItemDefinitions)).
+///
+/// Items keep the order they were registered in.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct ItemRegistry {
+    order: Vec<Item>,
+    map: HashMap<Item, ItemInfo>,
+}
+
+impl ItemRegistry {
+    /// Register an item, replacing any existing metadata for it.
+    pub fn insert(&mut self, id: &str, info: ItemInfo) -> &mut Self {
+        let item = Item::new(id);
+
+        if !self.map.contains_key(&item) {
+            self.order.push(item.clone());
+        }
+
+        self.map.insert(item, info);
+        self
+    }
+
+    /// Unregister an item, returning its metadata if it was registered.
+    pub fn remove(&mut self, item: &Item) -> Option<ItemInfo> {
+        self.order.retain(|i| i != item);
+        self.map.remove(item)
+    }
+
+    /// Returns the metadata of an item, if it is registered.
+    pub fn get(&self, item: &Item) -> Option<&ItemInfo> {
+        self.map.get(item)
+    }
+
+    /// Returns true if the item is registered.
+    pub fn contains(&self, item: &Item) -> bool {
+        self.map.contains_key(item)
+    }
+
+    /// Returns true if no items are registered.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns the position of an item in registration order, if it is registered.
+    pub fn index(&self, item: &Item) -> Option<usize> {
+        self.order.iter().position(|i| i == item)
+    }
+
+    /// Returns the name shown to the player for an item.
+    ///
+    /// Falls back to the item id if the item is not registered.
+    pub fn display_name<'a>(&'a self, item: &'a Item) -> &'a str {
+        self.get(item)
+            .map_or(item.as_str(), |info| info.name.as_str())
+    }
+
+    /// Iterate over all registered items, in registration order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Item, &ItemInfo)> {
+        self.order.iter().map(|item| (item, &self.map[item]))
+    }
+}
@@ -0,0 +1,297 @@
+use bevy::prelude::*;
+
+use crate::inventory::{
+    Item,
+    ItemRegistry,
+};
+
+/// A resource that stores the player's current inventory.
+///
+/// Items are kept in the order they were picked up, and each item is held as a stack with a count.
+/// An inventory can optionally have a fixed number of slots, see [`Inventory::with_capacity`].
+///
+/// Every change to the `Inventory` resource, including replacing it, is reported with
+/// [`ItemAdded`](crate::ItemAdded) and [`ItemRemoved`](crate::ItemRemoved) events at the end of the frame.
+/// Inventories stored elsewhere, like in `Characters` or `Containers`, don't send events.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct Inventory {
+    slots: Vec<Option<ItemStack>>,
+    capacity: Option<usize>,
+}
+
+/// A number of units of the same item, held in a single inventory slot.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Reflect)]
+pub struct ItemStack {
+    /// The item.
+    pub item: Item,
+
+    /// How many units of the item are held.
+    pub count: u32,
+}
+
+impl Inventory {
+    /// Returns a new, empty `Inventory` with a fixed number of slots.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: vec![None; capacity],
+            capacity: Some(capacity),
+        }
+    }
+
+    /// Returns the number of slots, if the inventory has a fixed capacity.
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// Returns the slots of the inventory, in order.
+    ///
+    /// Empty slots are only present when the inventory has a fixed capacity.
+    pub fn slots(&self) -> &[Option<ItemStack>] {
+        &self.slots
+    }
+
+    /// Iterate over the held stacks, in order.
+    pub fn stacks(&self) -> impl Iterator<Item = &ItemStack> {
+        self.slots.iter().flatten()
+    }
+
+    /// Iterate over the held items, in order.
+    pub fn items(&self) -> impl Iterator<Item = &Item> {
+        self.stacks().map(|stack| &stack.item)
+    }
+
+    /// Returns the number of different items held.
+    pub fn len(&self) -> usize {
+        self.stacks().count()
+    }
+
+    /// Returns true if no items are held.
+    pub fn is_empty(&self) -> bool {
+        self.stacks().next().is_none()
+    }
+
+    /// Returns true if the inventory has a fixed capacity and every slot is taken.
+    pub fn is_full(&self) -> bool {
+        self.capacity.is_some() && self.slots.iter().all(Option::is_some)
+    }
+
+    /// Returns true if at least one unit of the item is held.
+    pub fn contains(&self, item: &Item) -> bool {
+        self.slot_of(item).is_some()
+    }
+
+    /// Returns how many units of the item are held.
+    pub fn count(&self, item: &Item) -> u32 {
+        self.stacks()
+            .find(|stack| &stack.item == item)
+            .map_or(0, |stack| stack.count)
+    }
+
+    /// Returns the slot holding the item, if any.
+    pub fn slot_of(&self, item: &Item) -> Option<usize> {
+        self.slots
+            .iter()
+            .position(|slot| slot.as_ref().is_some_and(|stack| &stack.item == item))
+    }
+
+    /// Add `count` units of an item.
+    ///
+    /// Units are added to the existing stack if the item is already held, otherwise the item takes the first free slot.
+    /// Returns false if nothing could be added because the inventory is full.
+    pub fn add(&mut self, item: Item, count: u32) -> bool {
+        if count == 0 {
+            return true;
+        }
+
+        if let Some(index) = self.slot_of(&item) {
+            if let Some(stack) = &mut self.slots[index] {
+                stack.count += count;
+            }
+        } else if self.capacity.is_none() {
+            self.slots.push(Some(ItemStack { item, count }));
+        } else if let Some(slot) = self.slots.iter_mut().find(|slot| slot.is_none()) {
+            *slot = Some(ItemStack { item, count });
+        } else {
+            return false;
+        }
+
+        true
+    }
+
+    /// Add a single unit of an item.
+    ///
+    /// Alias for `add(item, 1)`.
+    pub fn insert(&mut self, item: Item) -> bool {
+        self.add(item, 1)
+    }
+
+    /// Remove `count` units of an item, leaving the other items in place.
+    ///
+    /// The item's slot is freed once no units are left.
+    /// Returns false, removing nothing, if fewer than `count` units are held.
+    pub fn remove(&mut self, item: &Item, count: u32) -> bool {
+        if count == 0 {
+            return true;
+        }
+
+        if let Some(index) = self.slot_of(item) {
+            if let Some(stack) = &mut self.slots[index] {
+                if stack.count < count {
+                    return false;
+                }
+
+                stack.count -= count;
+
+                if stack.count == 0 {
+                    if self.capacity.is_some() {
+                        self.slots[index] = None;
+                    } else {
+                        self.slots.remove(index);
+                    }
+                }
+
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Remove every unit of an item, returning how many were held.
+    pub fn remove_all(&mut self, item: &Item) -> u32 {
+        let count = self.count(item);
+        self.remove(item, count);
+        count
+    }
+
+    /// Move an item to the given slot, swapping it with whatever is already there.
+    ///
+    /// Returns false if the item is not held or the slot does not exist.
+    pub fn move_to_slot(&mut self, item: &Item, slot: usize) -> bool {
+        if let Some(index) = self.slot_of(item) {
+            if slot < self.slots.len() {
+                self.slots.swap(index, slot);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Sort the items in the order they were registered in the [`ItemRegistry`].
+    ///
+    /// Unregistered items are placed after registered ones, keeping their current order.
+    pub fn sort_by_registry(&mut self, registry: &ItemRegistry) {
+        self.sort_by_key(|item| registry.index(item).unwrap_or(usize::MAX));
+    }
+
+    /// Move the items with the given tag in the [`ItemRegistry`] to the front, keeping their current order.
+    pub fn sort_by_tag(&mut self, registry: &ItemRegistry, tag: &str) {
+        self.sort_by_key(|item| !registry.get(item).is_some_and(|info| info.has_tag(tag)));
+    }
+
+    /// Sort the items with a key extraction function, keeping the current order of equal items.
+    ///
+    /// Empty slots are moved to the end.
+    pub fn sort_by_key<K: Ord, F: FnMut(&Item) -> K>(&mut self, mut f: F) {
+        let mut stacks = self.slots.drain(..).flatten().collect::<Vec<_>>();
+
+        stacks.sort_by_key(|stack| f(&stack.item));
+
+        self.slots.extend(stacks.into_iter().map(Some));
+
+        if let Some(capacity) = self.capacity {
+            self.slots.resize(capacity, None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_stacks_units() {
+        let mut inventory = Inventory::default();
+        let key = Item::new("key");
+
+        assert!(inventory.add(key.clone(), 2));
+        assert!(inventory.insert(key.clone()));
+
+        assert_eq!(inventory.len(), 1);
+        assert_eq!(inventory.count(&key), 3);
+    }
+
+    #[test]
+    fn remove_frees_empty_stack() {
+        let mut inventory = Inventory::default();
+        let (key, coin) = (Item::new("key"), Item::new("coin"));
+
+        inventory.add(key.clone(), 2);
+        inventory.add(coin.clone(), 1);
+
+        assert!(!inventory.remove(&key, 3));
+        assert_eq!(inventory.count(&key), 2);
+
+        assert!(inventory.remove(&key, 2));
+        assert!(!inventory.contains(&key));
+        assert_eq!(inventory.items().collect::<Vec<_>>(), vec![&coin]);
+    }
+
+    #[test]
+    fn full_inventory_rejects_new_items() {
+        let mut inventory = Inventory::with_capacity(2);
+        let (key, coin, map) = (Item::new("key"), Item::new("coin"), Item::new("map"));
+
+        assert!(inventory.add(key.clone(), 1));
+        assert!(inventory.add(coin.clone(), 1));
+        assert!(inventory.is_full());
+
+        assert!(!inventory.add(map.clone(), 1));
+        assert!(!inventory.contains(&map));
+
+        // Existing stacks still grow when full
+        assert!(inventory.add(coin.clone(), 4));
+        assert_eq!(inventory.count(&coin), 5);
+    }
+
+    #[test]
+    fn removing_keeps_slot_positions_with_capacity() {
+        let mut inventory = Inventory::with_capacity(3);
+        let (key, coin, map) = (Item::new("key"), Item::new("coin"), Item::new("map"));
+
+        inventory.add(key.clone(), 1);
+        inventory.add(coin.clone(), 1);
+        inventory.remove(&key, 1);
+
+        assert_eq!(inventory.slot_of(&coin), Some(1));
+
+        inventory.add(map.clone(), 1);
+        assert_eq!(inventory.slot_of(&map), Some(0));
+    }
+
+    #[test]
+    fn sort_moves_empty_slots_to_the_end() {
+        let mut inventory = Inventory::with_capacity(3);
+
+        inventory.add(Item::new("b"), 1);
+        inventory.add(Item::new("a"), 1);
+        inventory.remove(&Item::new("b"), 1);
+        inventory.add(Item::new("c"), 1);
+        inventory.remove(&Item::new("c"), 1);
+        inventory.add(Item::new("b"), 1);
+
+        inventory.sort_by_key(|item| item.as_str().to_owned());
+
+        let names = inventory
+            .slots()
+            .iter()
+            .map(|slot| slot.as_ref().map(|stack| stack.item.as_str()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, vec![Some("a"), Some("b"), None]);
+    }
+}
@@ -8,43 +8,107 @@
 mod animation;
 mod audio;
 mod camera;
+mod character;
 mod commands;
 mod constants;
 mod cursor;
+mod examine;
+mod extras;
 mod interactives;
 mod inventory;
+mod loading;
 mod plugin;
 mod scene;
 mod state;
 mod textdisplay;
+mod transition;
+#[cfg(feature = "ui")]
+mod ui;
 
+#[cfg(feature = "serde")]
+pub use crate::inventory::ItemDefinitions;
+#[cfg(feature = "ui")]
+pub use crate::ui::{
+    InventoryUiPlugin,
+    InventoryUiStyle,
+    LoadingUiPlugin,
+    LoadingUiStyle,
+    NavigationUiPlugin,
+    NavigationUiStyle,
+};
 pub use crate::{
-    animation::AnimationServer,
+    animation::{
+        AnimationServer,
+        Easing,
+    },
     audio::AudioServer,
     camera::{
         BackToSpot,
         BackToState,
+        CameraArrived,
+        CameraMotion,
+        CameraMoveStarted,
+        CameraMoving,
+        CameraPath,
+        CameraPaths,
+        CameraSettings,
+        CameraShake,
         CameraSpot,
         CameraSpots,
         CurrentSpot,
+        EntrySpot,
+        FocusSpot,
+        GoBack,
         Ignores,
+        LookAround,
+        LookOffset,
+        LookSettings,
+        Navigate,
+        NavigationSettings,
+        Neighbors,
         NextSpot,
+        ScreenFade,
         SkipAnimation,
+        SpotDirection,
+        SpotHistory,
+        Zoom,
+    },
+    character::{
+        Character,
+        CharacterScenes,
+        Characters,
+        CurrentCharacter,
+        NextCharacter,
     },
     commands::{
         CommandsActionsExt,
         CommandsExt,
     },
-    constants::MAIN_CAMERA,
+    constants::{
+        INVENTORY_HEIGHT,
+        MAIN_CAMERA,
+        PATH_PREFIX,
+    },
     cursor::Cursor,
+    examine::{
+        ExamineHooks,
+        ExamineItem,
+        ExaminePart,
+        ExamineSettings,
+        Examining,
+    },
     interactives::{
         invalid_item_used,
         Action,
+        ActionDispatcher,
+        Container,
+        Containers,
         Description,
         Hovering,
         Interactive,
         ItemRef,
         MoveTo,
+        NoState,
         Portal,
         Prop,
         Simple,
@@ -54,18 +118,34 @@ pub use crate::{
         DraggingItem,
         Inventory,
         Item,
+        ItemAdded,
+        ItemBehavior,
+        ItemBehaviors,
+        ItemInfo,
+        ItemRegistry,
+        ItemRemoved,
+        ItemStack,
+        ItemsCombined,
+        PendingCombination,
+        Recipe,
         Recipes,
     },
+    loading::LoadingProgress,
     plugin::AdventurePlugin,
     scene::{
         AdventureScene,
         AppSceneStateExt,
+        MissingMainCamera,
     },
     state::WorldState,
     textdisplay::{
         Message,
         NewMessage,
     },
+    transition::{
+        SceneTransition,
+        Transitioning,
+    },
 };
 
 /// Prelude: convenient import for all the user-facing APIs provided by the crate
@@ -81,6 +161,10 @@ pub mod prelude {
         Description,
         Interactive,
         Item,
+        ItemBehavior,
+        ItemBehaviors,
+        ItemInfo,
+        ItemRegistry,
         Message,
         NewMessage,
         Simple,
@@ -0,0 +1,119 @@
+use bevy::{
+    asset::{
+        LoadState,
+        RecursiveDependencyLoadState,
+        UntypedAssetId,
+    },
+    prelude::*,
+};
+
+use crate::scene::AdventureSceneRoot;
+
+pub struct LoadingPlugin;
+
+impl Plugin for LoadingPlugin {
+    fn build(&self, app: &mut App) {
+        app ////
+            .register_type::<LoadingProgress>()
+            ////
+            .init_resource::<LoadingProgress>()
+            ////
+            .add_systems(First, track_loading);
+    }
+}
+
+/// Resource that tracks how many of the assets the current Scene needs have finished loading:
+/// the Scene itself, and the animations and audio clips registered by `AdventureScene::animations` and `AdventureScene::audio`
+/// when entering it.
+///
+/// Interactions and `NextSpot` wait until everything is loaded.
+/// Assets that fail to load are counted as finished, so a missing file doesn't block the game.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct LoadingProgress {
+    loaded: usize,
+    failed: usize,
+    total: usize,
+
+    #[reflect(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    assets: Vec<UntypedAssetId>,
+}
+
+impl LoadingProgress {
+    /// Returns how many assets have finished loading, including the ones that failed.
+    pub fn loaded(&self) -> usize {
+        self.loaded
+    }
+
+    /// Returns how many assets failed to load.
+    pub fn failed(&self) -> usize {
+        self.failed
+    }
+
+    /// Returns how many assets are tracked.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns how far along loading is, from 0 to 1.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn fraction(&self) -> f32 {
+        if self.total > 0 {
+            self.loaded as f32 / self.total as f32
+        } else {
+            1.0
+        }
+    }
+
+    /// Returns true once every tracked asset has finished loading.
+    pub fn finished(&self) -> bool {
+        self.loaded >= self.total
+    }
+
+    /// Track the assets registered by the Scene being entered, instead of those of the previous Scene.
+    pub(crate) fn track(&mut self, assets: Vec<UntypedAssetId>) {
+        self.assets = assets;
+    }
+}
+
+/// Returns true if the asset and its dependencies are loaded, or if loading it failed.
+fn is_done(asset_server: &AssetServer, id: UntypedAssetId) -> bool {
+    is_failed(asset_server, id)
+        || asset_server.recursive_dependency_load_state(id) == RecursiveDependencyLoadState::Loaded
+}
+
+/// Returns true if loading the asset or one of its dependencies failed.
+pub(crate) fn is_failed(asset_server: &AssetServer, id: impl Into<UntypedAssetId>) -> bool {
+    let id = id.into();
+
+    asset_server.load_state(id) == LoadState::Failed
+        || asset_server.recursive_dependency_load_state(id) == RecursiveDependencyLoadState::Failed
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn track_loading(
+    asset_server: Res<AssetServer>,
+    mut progress: ResMut<LoadingProgress>,
+    scenes: Query<&Handle<Scene>, With<AdventureSceneRoot>>,
+) {
+    let ids = scenes
+        .iter()
+        .map(|handle| handle.id().untyped())
+        .chain(progress.assets.iter().copied())
+        .collect::<Vec<_>>();
+
+    let loaded = ids.iter().filter(|id| is_done(&asset_server, **id)).count();
+    let failed = ids
+        .iter()
+        .filter(|id| is_failed(&asset_server, **id))
+        .count();
+    let total = ids.len();
+
+    if progress.loaded != loaded || progress.failed != failed || progress.total != total {
+        progress.loaded = loaded;
+        progress.failed = failed;
+        progress.total = total;
+    }
+}
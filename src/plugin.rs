@@ -9,12 +9,16 @@ use crate::{
     animation::AnimationPlugin,
     audio::AudioPlugin,
     camera::CameraPlugin,
+    character::CharacterPlugin,
     cursor::CursorPlugin,
+    examine::ExaminePlugin,
     interactives::InteractivesPlugin,
     inventory::InventoryPlugin,
+    loading::LoadingPlugin,
     scene::SceneManagerPlugin,
     state::WorldStatePlugin,
     textdisplay::TextDisplayPlugin,
+    transition::TransitionPlugin,
 };
 
 /// The main plugin that must be added to your app with `App::add_plugin`.
@@ -39,11 +43,15 @@ where
                 AnimationPlugin,
                 AudioPlugin,
                 CameraPlugin,
+                CharacterPlugin::<S>::default(),
                 CursorPlugin,
+                ExaminePlugin,
                 InteractivesPlugin::<S>::default(),
                 InventoryPlugin,
+                LoadingPlugin,
                 SceneManagerPlugin,
                 TextDisplayPlugin,
+                TransitionPlugin::<S>::default(),
                 WorldStatePlugin,
             ));
     }
@@ -18,14 +18,31 @@ use crate::{
         Tween,
     },
     camera::{
+        CameraMotion,
+        CameraMoving,
         CameraSpot,
+        EntrySpot,
+        FocusSpot,
         IsCameraSpot,
+        LookAround,
+        LookOffset,
+        Neighbors,
+        NextSpot,
+        SkipAnimation,
+        SpotHistory,
     },
+    extras::gltf_extras,
     interactives::{
+        hovering::HoverSystems,
         interactive,
         prepare_interaction,
         reset_interaction,
     },
+    loading::{
+        is_failed,
+        LoadingProgress,
+    },
+    transition::cover_scene,
     AudioServer,
     CurrentSpot,
     Interactive,
@@ -37,10 +54,29 @@ pub struct SceneManagerPlugin;
 impl Plugin for SceneManagerPlugin {
     fn build(&self, app: &mut App) {
         app ////
-            .add_systems(Update, run_hooks);
+            .add_event::<MissingMainCamera>()
+            ////
+            .add_systems(Update, run_hooks)
+            .add_systems(Last, check_main_camera);
     }
 }
 
+/// An event that fires when a Scene has finished loading without a camera named `MAIN_CAMERA`.
+///
+/// Without it, the Camera is deactivated and the `CurrentSpot` is empty, so nothing can be seen or interacted with.
+#[derive(Event, Clone, Debug)]
+pub struct MissingMainCamera {
+    /// The asset path of the Scene.
+    pub scene: String,
+
+    /// The names of the cameras that were found in the Scene.
+    pub cameras: Vec<String>,
+}
+
+/// Marker component for the root entity of a Scene loaded with `SceneManager::load`.
+#[derive(Component, Debug)]
+pub struct AdventureSceneRoot;
+
 #[derive(Component, Debug)]
 pub struct SceneHooked;
 
@@ -76,17 +112,80 @@ fn run_hooks(
     }
 }
 
+#[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::type_complexity)]
+fn check_main_camera(
+    at_spot: Res<CurrentSpot>,
+    spawner: Res<SceneSpawner>,
+    asset_server: Res<AssetServer>,
+    mut missing: EventWriter<MissingMainCamera>,
+    loaded: Query<(&Handle<Scene>, &SceneInstance), (With<AdventureSceneRoot>, Added<SceneHooked>)>,
+    spots: Query<&Name, With<IsCameraSpot>>,
+    mut cameras: Query<&mut Camera, With<Tween<Transform>>>,
+) {
+    for (handle, instance) in &loaded {
+        if at_spot.is_some() {
+            continue;
+        }
+
+        let scene = asset_server
+            .get_path(handle.id())
+            .map_or_else(|| format!("{handle:?}"), |path| path.to_string());
+
+        let names = spawner
+            .iter_instance_entities(**instance)
+            .filter_map(|entity| spots.get(entity).ok())
+            .map(|name| name.as_str().to_owned())
+            .collect::<Vec<_>>();
+
+        error!(
+            "Scene {:?} has no camera named {:?}, the cameras in the scene are {:?}",
+            scene, MAIN_CAMERA, names
+        );
+
+        missing.send(MissingMainCamera {
+            scene,
+            cameras: names,
+        });
+
+        // The Camera is kept between scenes, and would still show the previous one
+        for mut camera in &mut cameras {
+            camera.is_active = false;
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
 #[derive(SystemParam)]
 pub struct SceneManager<'w, 's> {
     commands: Commands<'w, 's>,
     asset_server: Res<'w, AssetServer>,
+    progress: Res<'w, LoadingProgress>,
     cameras: Query<'w, 's, Entity, With<Camera>>,
-    unloaded: Query<'w, 's, Entity, (With<SceneHook>, Without<SceneHooked>)>,
+    unloaded: Query<
+        'w,
+        's,
+        &'static Handle<Scene>,
+        (
+            With<AdventureSceneRoot>,
+            With<SceneHook>,
+            Without<SceneHooked>,
+        ),
+    >,
 }
 
 impl<'w, 's> SceneManager<'w, 's> {
+    /// Returns true once every Scene has been spawned, and every asset it registered has finished loading.
+    ///
+    /// Models loaded with `load_model` are not waited for, and a Scene that failed to load counts as ready.
     pub fn ready(&self) -> bool {
-        self.unloaded.is_empty()
+        // A Scene that failed to load is never spawned, so it isn't waited for
+        let spawned = self
+            .unloaded
+            .iter()
+            .all(|handle| is_failed(&self.asset_server, handle.id()));
+
+        spawned && self.progress.finished()
     }
 
     pub fn load<'a, P, F>(&mut self, path: P, hook: F) -> impl Bundle
@@ -103,17 +202,23 @@ impl<'w, 's> SceneManager<'w, 's> {
         }
 
         (
+            AdventureSceneRoot,
             SceneBundle {
                 scene: self.asset_server.load(path),
                 ..default()
             },
             SceneHook::new(move |entity, commands| {
+                let extras = gltf_extras(entity);
+
                 if entity.contains::<Camera>() {
                     if let Some(name) = entity.get::<Name>() {
                         if name.as_str() == MAIN_CAMERA {
                             let tf = entity.get::<Transform>().unwrap();
+                            let projection =
+                                entity.get::<Projection>().cloned().unwrap_or_default();
 
-                            let spot = CameraSpot::new(name, entity.id(), *tf);
+                            let spot = CameraSpot::new(name, entity.id(), *tf)
+                                .with_projection(projection.clone());
 
                             commands.commands().insert_resource(CurrentSpot::new(spot));
 
@@ -122,14 +227,31 @@ impl<'w, 's> SceneManager<'w, 's> {
                                 .entity(camera)
                                 .insert(Camera3dBundle {
                                     transform: *tf,
+                                    projection: projection.clone(),
                                     ..default()
                                 })
-                                .insert(Tween::new(*tf));
+                                .insert((Tween::new(*tf), Tween::new(projection)));
                         }
                     }
 
                     commands.remove::<Camera>();
                     commands.insert(IsCameraSpot);
+
+                    if let Some(extras) = &extras {
+                        if let Some(motion) = CameraMotion::from_extras(extras) {
+                            commands.insert(motion);
+                        }
+
+                        if let Some(look) = LookAround::from_extras(extras) {
+                            commands.insert(look);
+                        }
+
+                        if let Some(neighbors) = Neighbors::from_extras(extras) {
+                            commands.insert(neighbors);
+                        }
+                    }
+                } else if let Some(focus) = extras.as_ref().and_then(FocusSpot::from_extras) {
+                    commands.insert(focus);
                 }
 
                 if let Some(light) = entity.get::<PointLight>() {
@@ -143,6 +265,20 @@ impl<'w, 's> SceneManager<'w, 's> {
         )
     }
 
+    pub fn load_model<'a, P, F>(&mut self, path: P, hook: F) -> (SceneBundle, SceneHook)
+    where
+        P: Into<AssetPath<'a>>,
+        F: Fn(&EntityRef, &mut EntityCommands) + Send + Sync + 'static,
+    {
+        (
+            SceneBundle {
+                scene: self.asset_server.load(path),
+                ..default()
+            },
+            SceneHook::new(hook),
+        )
+    }
+
     pub fn spawn<'a, P, F>(&mut self, path: P, hook: F)
     where
         P: Into<AssetPath<'a>>,
@@ -199,7 +335,7 @@ impl AppSceneStateExt for App {
         self ////
             .add_systems(First, reset_interaction)
             .add_systems(PreUpdate, prepare_interaction::<S::State>)
-            .add_systems(OnEnter(S::state()), spawn_scene::<S>)
+            .add_systems(OnEnter(S::state()), (spawn_scene::<S>, cover_scene))
             .add_systems(OnExit(S::state()), cleanup_scene)
     }
 
@@ -208,17 +344,63 @@ impl AppSceneStateExt for App {
         S: AdventureScene + 'static,
         I: Interactive + Component,
     {
-        self.add_systems(Update, interactive::<I>.run_if(in_state(S::state())))
+        self.add_systems(
+            Update,
+            interactive::<I>
+                .run_if(in_state(S::state()))
+                .after(HoverSystems),
+        )
     }
 }
 
+#[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::too_many_arguments)]
 fn spawn_scene<S: AdventureScene + 'static>(
+    mut commands: Commands,
     mut manager: SceneManager,
     mut animation_server: AnimationServer,
     mut audio_server: AudioServer,
+    mut history: ResMut<SpotHistory>,
+    mut moving: ResMut<CameraMoving>,
+    mut offset: ResMut<LookOffset>,
+    entry: Option<Res<EntrySpot>>,
 ) {
+    history.enter(S::scene());
+
+    // Nothing carries over from the previous scene
+    *moving = CameraMoving::default();
+    *offset = LookOffset::default();
+
+    // Set by the scene hook, once the Main Camera is spawned
+    commands.insert_resource(CurrentSpot::default());
+
+    // The Camera starts at MAIN_CAMERA, and jumps to the entry spot once the scene is ready
+    if let Some(entry) = entry {
+        commands.remove_resource::<EntrySpot>();
+
+        if entry.0 != MAIN_CAMERA {
+            commands.insert_resource(NextSpot(entry.0.clone()));
+            commands.insert_resource(SkipAnimation);
+        }
+    }
     S::animations(&mut animation_server);
     S::audio(&mut audio_server);
+
+    // Only the assets registered by this scene are waited for
+    let assets = animation_server
+        .take_registered()
+        .into_iter()
+        .map(|id| id.untyped())
+        .chain(
+            audio_server
+                .take_registered()
+                .into_iter()
+                .map(|id| id.untyped()),
+        )
+        .collect();
+
+    commands.add(move |world: &mut World| world.resource_mut::<LoadingProgress>().track(assets));
+
     manager.spawn(S::scene(), S::spawn);
 }
 
@@ -5,7 +5,10 @@ use bevy::{
     prelude::*,
 };
 
-use crate::Item;
+use crate::{
+    Item,
+    ItemRegistry,
+};
 
 pub struct TextDisplayPlugin;
 
@@ -23,8 +26,8 @@ pub enum Message {
     /// Free-form text.
     Text(String),
 
-    /// The Item that has just been picked up.
-    ItemPickup(Item),
+    /// The Item that has just been picked up, and how many units of it.
+    ItemPickup(Item, u32),
 
     /// The result of a successful item combination (`source -> destination = result`).
     ItemCombine {
@@ -38,9 +41,33 @@ pub enum Message {
         result: Item,
     },
 
+    /// The result of a successful combination of any other number of items.
+    ItemCombineMany {
+        /// The combined Items, including tools
+        items: Vec<Item>,
+
+        /// Result Items
+        results: Vec<Item>,
+    },
+
+    /// The Item that has just been put into a container.
+    ItemStored(Item),
+
+    /// The Items inside a container that has just been opened.
+    ContainerContents(Vec<Item>),
+
+    /// The Items that have just been taken out of a container.
+    ItemsTaken(Vec<Item>),
+
+    /// The Items combined so far, when they are part of a recipe that needs more items.
+    PartialItemCombination(Vec<Item>),
+
     /// The result of an unsuccesful Item combination.
     InvalidItemCombination,
 
+    /// A message sent when items can't be combined because their results don't fit in the Inventory.
+    InventoryFull,
+
     /// A message sent when the wrong Item is used on an Interactive.
     InvalidItemUsed,
 }
@@ -50,6 +77,52 @@ impl Message {
     pub fn new(text: &str) -> Self {
         Self::Text(text.to_owned())
     }
+
+    /// Returns a default English rendering of the message,
+    /// using the display names from the [`ItemRegistry`] for any items it mentions.
+    pub fn text(&self, registry: &ItemRegistry) -> String {
+        match self {
+            Self::Text(text) => text.clone(),
+            Self::ItemPickup(item, 1) => format!("Picked up {}.", registry.display_name(item)),
+            Self::ItemPickup(item, count) => {
+                format!("Picked up {} x{count}.", registry.display_name(item))
+            }
+            Self::ItemCombine { src, dst, result } => format!(
+                "Combined {} with {} to make {}.",
+                registry.display_name(src),
+                registry.display_name(dst),
+                registry.display_name(result)
+            ),
+            Self::ItemCombineMany { items, results } => format!(
+                "Combined {} to make {}.",
+                list(registry, items),
+                list(registry, results)
+            ),
+            Self::ItemStored(item) => format!("Put away {}.", registry.display_name(item)),
+            Self::ContainerContents(items) if items.is_empty() => "It's empty.".to_owned(),
+            Self::ContainerContents(items) => format!("It contains {}.", list(registry, items)),
+            Self::ItemsTaken(items) if items.is_empty() => "There's nothing to take.".to_owned(),
+            Self::ItemsTaken(items) => format!("Took {}.", list(registry, items)),
+            Self::PartialItemCombination(items) => {
+                format!("{} need something else.", list(registry, items))
+            }
+            Self::InvalidItemCombination => "Those items can't be combined.".to_owned(),
+            Self::InventoryFull => "There's no room for that.".to_owned(),
+            Self::InvalidItemUsed => "That doesn't work.".to_owned(),
+        }
+    }
+}
+
+fn list(registry: &ItemRegistry, items: &[Item]) -> String {
+    let names = items
+        .iter()
+        .map(|item| registry.display_name(item))
+        .collect::<Vec<_>>();
+
+    match names.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} and {last}", rest.join(", ")),
+        _ => names.concat(),
+    }
 }
 
 /// An event that triggers whenever an `Action::Message` is executed.
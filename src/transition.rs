@@ -0,0 +1,183 @@
+use std::marker::PhantomData;
+
+use bevy::{
+    ecs::schedule::{
+        apply_state_transition,
+        States,
+    },
+    prelude::*,
+};
+
+use crate::{
+    camera::{
+        NextSpot,
+        ScreenFade,
+    },
+    scene::SceneManager,
+};
+
+pub struct TransitionPlugin<S>(PhantomData<S>);
+
+impl<S> Default for TransitionPlugin<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S> Plugin for TransitionPlugin<S>
+where
+    S: States,
+{
+    fn build(&self, app: &mut App) {
+        app ////
+            .register_type::<SceneTransition>()
+            ////
+            .init_resource::<SceneTransition>()
+            .init_resource::<PendingTransition<S>>()
+            ////
+            // Runs right before the state changes, so `NextState` from any schedule is held
+            .add_systems(
+                StateTransition,
+                hold_transition::<S>.before(apply_state_transition::<S>),
+            )
+            .add_systems(Last, reveal_scene);
+    }
+}
+
+/// A resource that configures what happens on screen when the state changes from one scene to another.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Clone, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub enum SceneTransition {
+    /// The old scene disappears right away, and the new scene appears as soon as it is loaded.
+    #[default]
+    Instant,
+
+    /// The screen fades out to a color before leaving the old scene,
+    /// and is held until the new scene is ready before fading back in.
+    Fade {
+        /// The color to fade to.
+        color: Color,
+
+        /// How long fading out takes, in seconds.
+        fade_out: f32,
+
+        /// How long fading in takes, in seconds.
+        fade_in: f32,
+    },
+}
+
+impl SceneTransition {
+    /// Returns a `SceneTransition` that fades out to the given color and back in, each over the given duration in seconds.
+    pub fn fade(color: Color, duration: f32) -> Self {
+        Self::Fade {
+            color,
+            fade_out: duration,
+            fade_in: duration,
+        }
+    }
+}
+
+/// Resource that exists while a [`SceneTransition`] is in progress.
+///
+/// Nothing can be interacted with during a transition.
+#[derive(Resource, Debug, Default)]
+pub struct Transitioning {
+    entered: bool,
+}
+
+/// Resource that stores the state to change to once the screen has faded out.
+#[derive(Resource, Debug)]
+struct PendingTransition<S> {
+    state: Option<S>,
+}
+
+impl<S> Default for PendingTransition<S> {
+    fn default() -> Self {
+        Self { state: None }
+    }
+}
+
+/// Covers the screen when entering a scene, until the scene is ready.
+///
+/// Runs in `OnEnter` for every scene, so state changes that were not held by the transition are covered too.
+#[allow(clippy::needless_pass_by_value)]
+pub(crate) fn cover_scene(
+    mut commands: Commands,
+    settings: Res<SceneTransition>,
+    mut fade: ResMut<ScreenFade>,
+) {
+    if let SceneTransition::Fade { color, .. } = *settings {
+        if !fade.is_opaque() {
+            fade.fade_out(color, 0.0);
+        }
+
+        commands.insert_resource(Transitioning { entered: true });
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn hold_transition<S: States>(
+    mut commands: Commands,
+    settings: Res<SceneTransition>,
+    mut fade: ResMut<ScreenFade>,
+    mut pending: ResMut<PendingTransition<S>>,
+    current: Option<Res<State<S>>>,
+    next: Option<ResMut<NextState<S>>>,
+) {
+    let Some(mut next) = next else {
+        return;
+    };
+
+    let SceneTransition::Fade {
+        color, fade_out, ..
+    } = *settings
+    else {
+        return;
+    };
+
+    if pending.state.is_some() && fade.is_opaque() {
+        // Let the state change through, it is applied right after this system
+        next.0 = pending.state.take();
+        return;
+    }
+
+    // Changing to the current state doesn't change the scene
+    if next.0.is_none() || current.is_some_and(|current| next.0.as_ref() == Some(current.get())) {
+        return;
+    }
+
+    if let Some(state) = next.0.take() {
+        if pending.state.is_none() {
+            fade.fade_out(color, fade_out);
+            commands.insert_resource(Transitioning::default());
+        }
+
+        pending.state = Some(state);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn reveal_scene(
+    mut commands: Commands,
+    manager: SceneManager,
+    settings: Res<SceneTransition>,
+    mut fade: ResMut<ScreenFade>,
+    transitioning: Option<Res<Transitioning>>,
+    next: Option<Res<NextSpot>>,
+) {
+    if !transitioning.is_some_and(|transitioning| transitioning.entered) {
+        return;
+    }
+
+    // Wait until the scene is loaded, and the Camera is at the entry spot
+    if !manager.ready() || next.is_some() {
+        return;
+    }
+
+    if let SceneTransition::Fade { fade_in, .. } = *settings {
+        fade.fade_in(fade_in);
+    }
+
+    commands.remove_resource::<Transitioning>();
+}
@@ -0,0 +1,418 @@
+use bevy::{
+    input::mouse::{
+        MouseScrollUnit,
+        MouseWheel,
+    },
+    prelude::*,
+};
+
+use crate::{
+    inventory::{
+        recipes::handle_combine,
+        DraggingItem,
+        Inventory,
+        Item,
+        ItemRegistry,
+        ItemStack,
+    },
+    Cursor,
+    ExamineItem,
+    INVENTORY_HEIGHT,
+};
+
+/// Plugin that adds a default inventory bar along the bottom of the window.
+///
+/// Each item in the [`Inventory`] is shown as a slot, labelled with its icon and display name from the [`ItemRegistry`]
+/// and how many units of it are held.
+/// Slots can be dragged onto another item to combine them,
+/// or onto an object in the scene to use it. Right-clicking a slot examines the item.
+/// The bar scrolls with the mouse wheel once it is full.
+///
+/// The look of the bar is configured with the [`InventoryUiStyle`] resource.
+pub struct InventoryUiPlugin;
+
+impl Plugin for InventoryUiPlugin {
+    fn build(&self, app: &mut App) {
+        app ////
+            .register_type::<InventoryUiStyle>()
+            ////
+            .init_resource::<InventoryUiStyle>()
+            ////
+            .add_systems(Startup, spawn_inventory_bar)
+            .add_systems(
+                Update,
+                (
+                    rebuild_slots,
+                    scroll_slots,
+                    drag_slots.before(handle_combine),
+                    examine_slots,
+                    highlight_slots,
+                    update_ghost,
+                )
+                    .chain(),
+            )
+            .add_systems(Last, end_drag);
+    }
+}
+
+/// A resource that configures the inventory bar added by [`InventoryUiPlugin`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct InventoryUiStyle {
+    /// Background color of the bar.
+    pub background: Color,
+
+    /// Background color of an idle slot.
+    pub slot: Color,
+
+    /// Background color of the slot under the cursor.
+    pub hovered: Color,
+
+    /// Background color of the slot that is being dragged.
+    pub dragging: Color,
+
+    /// Width and height of a slot, in logical pixels.
+    pub slot_size: f32,
+
+    /// Space between slots, in logical pixels.
+    pub spacing: f32,
+
+    /// Color of the item labels.
+    pub text: Color,
+
+    /// Font size of the item labels.
+    pub font_size: f32,
+
+    /// Width and height of the item icons, in logical pixels.
+    pub icon_size: f32,
+
+    /// Distance scrolled for each line of mouse wheel movement, in logical pixels.
+    pub scroll_speed: f32,
+}
+
+impl Default for InventoryUiStyle {
+    fn default() -> Self {
+        Self {
+            background: Color::rgba(0.0, 0.0, 0.0, 0.6),
+            slot: Color::rgba(1.0, 1.0, 1.0, 0.1),
+            hovered: Color::rgba(1.0, 1.0, 1.0, 0.25),
+            dragging: Color::rgba(1.0, 0.85, 0.4, 0.4),
+            slot_size: 80.0,
+            spacing: 10.0,
+            text: Color::WHITE,
+            font_size: 14.0,
+            icon_size: 48.0,
+            scroll_speed: 40.0,
+        }
+    }
+}
+
+impl InventoryUiStyle {
+    fn text_style(&self) -> TextStyle {
+        TextStyle {
+            font_size: self.font_size,
+            color: self.text,
+            ..default()
+        }
+    }
+}
+
+#[derive(Component)]
+struct InventoryBar;
+
+#[derive(Component, Default)]
+struct InventoryRow {
+    scroll: f32,
+}
+
+#[derive(Component)]
+struct InventorySlot {
+    index: usize,
+    item: Option<Item>,
+}
+
+#[derive(Component)]
+struct DragGhost;
+
+#[allow(clippy::needless_pass_by_value)]
+fn spawn_inventory_bar(mut commands: Commands, style: Res<InventoryUiStyle>) {
+    commands
+        .spawn((Name::new("Inventory Bar"), InventoryBar, NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                right: Val::Px(0.0),
+                bottom: Val::Px(0.0),
+                height: Val::Px(INVENTORY_HEIGHT),
+                align_items: AlignItems::Center,
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            background_color: style.background.into(),
+            ..default()
+        }))
+        .with_children(|parent| {
+            parent.spawn((InventoryRow::default(), NodeBundle {
+                style: Style {
+                    align_items: AlignItems::Center,
+                    flex_shrink: 0.0,
+                    padding: UiRect::horizontal(Val::Px(style.spacing)),
+                    column_gap: Val::Px(style.spacing),
+                    ..default()
+                },
+                ..default()
+            }));
+        });
+
+    commands.spawn((Name::new("Inventory Drag Ghost"), DragGhost, TextBundle {
+        visibility: Visibility::Hidden,
+        z_index: ZIndex::Global(i32::MAX),
+        ..TextBundle::from_section("", style.text_style()).with_style(Style {
+            position_type: PositionType::Absolute,
+            ..default()
+        })
+    }));
+}
+
+fn spawn_slot(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    registry: &ItemRegistry,
+    style: &InventoryUiStyle,
+    index: usize,
+    stack: Option<&ItemStack>,
+) {
+    let slot = InventorySlot {
+        index,
+        item: stack.map(|stack| stack.item.clone()),
+    };
+
+    parent
+        .spawn((slot, ButtonBundle {
+            style: Style {
+                width: Val::Px(style.slot_size),
+                height: Val::Px(style.slot_size),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_shrink: 0.0,
+                ..default()
+            },
+            background_color: style.slot.into(),
+            ..default()
+        }))
+        .with_children(|parent| {
+            let Some(ItemStack { item, count }) = stack else {
+                return;
+            };
+
+            if let Some(icon) = registry.get(item).and_then(|info| info.icon.clone()) {
+                parent.spawn(ImageBundle {
+                    style: Style {
+                        width: Val::Px(style.icon_size),
+                        height: Val::Px(style.icon_size),
+                        ..default()
+                    },
+                    image: UiImage::new(asset_server.load(icon)),
+                    ..default()
+                });
+            }
+
+            let name = registry.display_name(item);
+
+            let label = if *count > 1 {
+                format!("{name} x{count}")
+            } else {
+                name.to_owned()
+            };
+
+            parent.spawn(
+                TextBundle::from_section(label, style.text_style())
+                    .with_text_alignment(TextAlignment::Center),
+            );
+        });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn rebuild_slots(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    inventory: Res<Inventory>,
+    registry: Res<ItemRegistry>,
+    style: Res<InventoryUiStyle>,
+    mut bars: Query<&mut BackgroundColor, With<InventoryBar>>,
+    rows: Query<Entity, With<InventoryRow>>,
+) {
+    if !inventory.is_changed() && !registry.is_changed() && !style.is_changed() {
+        return;
+    }
+
+    for mut background in &mut bars {
+        background.0 = style.background;
+    }
+
+    for row in &rows {
+        commands
+            .entity(row)
+            .despawn_descendants()
+            .with_children(|parent| {
+                for (index, item) in inventory.slots().iter().enumerate() {
+                    spawn_slot(
+                        parent,
+                        &asset_server,
+                        &registry,
+                        &style,
+                        index,
+                        item.as_ref(),
+                    );
+                }
+            });
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn scroll_slots(
+    style: Res<InventoryUiStyle>,
+    cursor: Res<Cursor>,
+    mut wheel: EventReader<MouseWheel>,
+    windows: Query<&Window>,
+    bars: Query<&Node, With<InventoryBar>>,
+    mut rows: Query<(&mut InventoryRow, &mut Style, &Node)>,
+) {
+    let mut delta = 0.0;
+
+    for event in wheel.read() {
+        delta += match event.unit {
+            MouseScrollUnit::Line => event.y * style.scroll_speed,
+            MouseScrollUnit::Pixel => event.y,
+        };
+    }
+
+    let over_bar = windows
+        .get_single()
+        .is_ok_and(|window| cursor.position().y >= window.height() - INVENTORY_HEIGHT);
+
+    if !over_bar {
+        delta = 0.0;
+    }
+
+    if let Ok(bar) = bars.get_single() {
+        for (mut row, mut layout, node) in &mut rows {
+            let max = (node.size().x - bar.size().x).max(0.0);
+
+            row.scroll = (row.scroll - delta).clamp(0.0, max);
+
+            if layout.left != Val::Px(-row.scroll) {
+                layout.left = Val::Px(-row.scroll);
+            }
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn drag_slots(
+    input: Res<Input<MouseButton>>,
+    mut dragging: ResMut<DraggingItem>,
+    mut inventory: ResMut<Inventory>,
+    slots: Query<(&InventorySlot, &Interaction)>,
+) {
+    let mut hovered = None;
+
+    for (slot, interaction) in &slots {
+        match interaction {
+            Interaction::Pressed if dragging.src.is_none() => {
+                dragging.src.clone_from(&slot.item);
+            }
+            Interaction::Hovered => hovered = Some(slot),
+            _ => (),
+        }
+    }
+
+    if let Some(src) = &dragging.src {
+        // The pressed slot turns Hovered on release, which is not a drop on another item
+        let dst = hovered
+            .and_then(|slot| slot.item.clone())
+            .filter(|item| item != src);
+
+        // Dropping an item on an empty slot moves it there
+        if let Some(slot) = hovered {
+            if slot.item.is_none() && input.just_released(MouseButton::Left) {
+                inventory.move_to_slot(src, slot.index);
+            }
+        }
+
+        if dragging.dst != dst {
+            dragging.dst = dst;
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn examine_slots(
+    mut commands: Commands,
+    input: Res<Input<MouseButton>>,
+    slots: Query<(&InventorySlot, &Interaction)>,
+) {
+    if input.just_pressed(MouseButton::Right) {
+        for (slot, interaction) in &slots {
+            if let (Some(item), Interaction::Hovered) = (&slot.item, interaction) {
+                commands.insert_resource(ExamineItem(item.clone()));
+            }
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn highlight_slots(
+    style: Res<InventoryUiStyle>,
+    dragging: Res<DraggingItem>,
+    mut slots: Query<(&InventorySlot, &Interaction, &mut BackgroundColor)>,
+) {
+    for (slot, interaction, mut background) in &mut slots {
+        let color = if slot.item.is_some() && dragging.src == slot.item {
+            style.dragging
+        } else if let Interaction::Hovered = interaction {
+            style.hovered
+        } else {
+            style.slot
+        };
+
+        if background.0 != color {
+            background.0 = color;
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn update_ghost(
+    registry: Res<ItemRegistry>,
+    style: Res<InventoryUiStyle>,
+    cursor: Res<Cursor>,
+    dragging: Res<DraggingItem>,
+    mut ghosts: Query<(&mut Style, &mut Text, &mut Visibility), With<DragGhost>>,
+) {
+    for (mut layout, mut text, mut visibility) in &mut ghosts {
+        if let Some(item) = &dragging.src {
+            let name = registry.display_name(item);
+
+            *visibility = Visibility::Inherited;
+
+            layout.left = Val::Px(cursor.position().x + 12.0);
+            layout.top = Val::Px(cursor.position().y + 12.0);
+
+            if text.sections[0].value != name || style.is_changed() {
+                *text = Text::from_section(name, style.text_style());
+            }
+        } else if *visibility != Visibility::Hidden {
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn end_drag(input: Res<Input<MouseButton>>, mut dragging: ResMut<DraggingItem>) {
+    if input.just_released(MouseButton::Left) && dragging.is_dragging() {
+        *dragging = DraggingItem::default();
+    }
+}
@@ -0,0 +1,169 @@
+use bevy::prelude::*;
+
+use crate::{
+    loading::LoadingProgress,
+    scene::SceneManager,
+};
+
+/// Plugin that covers the window with a loading screen and a progress bar while a Scene and its assets are loading.
+///
+/// The look of the loading screen is configured with the [`LoadingUiStyle`] resource.
+pub struct LoadingUiPlugin;
+
+impl Plugin for LoadingUiPlugin {
+    fn build(&self, app: &mut App) {
+        app ////
+            .register_type::<LoadingUiStyle>()
+            ////
+            .init_resource::<LoadingUiStyle>()
+            ////
+            .add_systems(Startup, spawn_loading_screen)
+            .add_systems(Update, update_loading_screen);
+    }
+}
+
+/// A resource that configures the loading screen added by [`LoadingUiPlugin`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct LoadingUiStyle {
+    /// Color of the background that covers the window.
+    pub background: Color,
+
+    /// Color of the empty part of the progress bar.
+    pub track: Color,
+
+    /// Color of the filled part of the progress bar.
+    pub bar: Color,
+
+    /// Color of the label above the progress bar.
+    pub text: Color,
+
+    /// Font size of the label above the progress bar.
+    pub font_size: f32,
+
+    /// Width of the progress bar, in logical pixels.
+    pub width: f32,
+
+    /// Height of the progress bar, in logical pixels.
+    pub height: f32,
+}
+
+impl Default for LoadingUiStyle {
+    fn default() -> Self {
+        Self {
+            background: Color::BLACK,
+            track: Color::rgb(0.2, 0.2, 0.2),
+            bar: Color::WHITE,
+            text: Color::WHITE,
+            font_size: 24.0,
+            width: 320.0,
+            height: 8.0,
+        }
+    }
+}
+
+#[derive(Component)]
+struct LoadingScreen;
+
+#[derive(Component)]
+struct LoadingBar;
+
+#[derive(Component)]
+struct LoadingLabel;
+
+#[allow(clippy::needless_pass_by_value)]
+fn spawn_loading_screen(mut commands: Commands, style: Res<LoadingUiStyle>) {
+    commands
+        .spawn((Name::new("Loading Screen"), LoadingScreen, NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(style.font_size / 2.0),
+                ..default()
+            },
+            background_color: style.background.into(),
+            visibility: Visibility::Hidden,
+            z_index: ZIndex::Global(i32::MAX),
+            ..default()
+        }))
+        .with_children(|parent| {
+            parent.spawn((
+                LoadingLabel,
+                TextBundle::from_section("Loading", TextStyle {
+                    font_size: style.font_size,
+                    color: style.text,
+                    ..default()
+                }),
+            ));
+
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(style.width),
+                        height: Val::Px(style.height),
+                        ..default()
+                    },
+                    background_color: style.track.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((LoadingBar, NodeBundle {
+                        style: Style {
+                            width: Val::Percent(0.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        background_color: style.bar.into(),
+                        ..default()
+                    }));
+                });
+        });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn update_loading_screen(
+    scene: SceneManager,
+    progress: Res<LoadingProgress>,
+    mut screens: Query<&mut Visibility, With<LoadingScreen>>,
+    mut bars: Query<&mut Style, With<LoadingBar>>,
+    mut labels: Query<&mut Text, With<LoadingLabel>>,
+) {
+    let shown = !scene.ready();
+
+    let next = if shown {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+
+    for mut visibility in &mut screens {
+        if *visibility != next {
+            *visibility = next;
+        }
+    }
+
+    if !shown {
+        return;
+    }
+
+    let percent = progress.fraction() * 100.0;
+
+    for mut style in &mut bars {
+        if style.width != Val::Percent(percent) {
+            style.width = Val::Percent(percent);
+        }
+    }
+
+    let label = format!("Loading {percent:.0}%");
+
+    for mut text in &mut labels {
+        if text.sections[0].value != label {
+            text.sections[0].value.clone_from(&label);
+        }
+    }
+}
@@ -0,0 +1,16 @@
+mod inventory;
+mod loading;
+mod navigation;
+
+pub use inventory::{
+    InventoryUiPlugin,
+    InventoryUiStyle,
+};
+pub use loading::{
+    LoadingUiPlugin,
+    LoadingUiStyle,
+};
+pub use navigation::{
+    NavigationUiPlugin,
+    NavigationUiStyle,
+};
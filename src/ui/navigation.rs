@@ -0,0 +1,234 @@
+use bevy::prelude::*;
+
+use crate::{
+    camera::{
+        look::look_around,
+        CameraMoving,
+        CurrentSpot,
+        LookOffset,
+        Navigate,
+        Neighbors,
+        SpotDirection,
+    },
+    interactives::{
+        hovering::{
+            hovering_raycast,
+            HoverSystems,
+        },
+        Hovering,
+    },
+    Examining,
+    INVENTORY_HEIGHT,
+};
+
+/// Plugin that adds arrows along the edges of the window, which move to the [`Neighbors`] of the current `CameraSpot`.
+///
+/// An arrow is only shown if there is a neighboring spot in its direction.
+/// The scene behind an arrow can't be interacted with, and hovering an arrow doesn't pan the view of a `LookAround` spot.
+///
+/// The look of the arrows is configured with the [`NavigationUiStyle`] resource.
+pub struct NavigationUiPlugin;
+
+impl Plugin for NavigationUiPlugin {
+    fn build(&self, app: &mut App) {
+        app ////
+            .register_type::<NavigationUiStyle>()
+            ////
+            .init_resource::<NavigationUiStyle>()
+            ////
+            .add_systems(Startup, spawn_arrows)
+            .add_systems(
+                Update,
+                (
+                    block_edge_pan.before(look_around),
+                    block_hovering.in_set(HoverSystems).after(hovering_raycast),
+                    (click_arrows, update_arrows).chain(),
+                ),
+            );
+    }
+}
+
+/// A resource that configures the arrows added by [`NavigationUiPlugin`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Resource, Clone, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct NavigationUiStyle {
+    /// Background color of an idle arrow.
+    pub background: Color,
+
+    /// Background color of the arrow under the cursor.
+    pub hovered: Color,
+
+    /// Color of the arrow glyphs.
+    pub text: Color,
+
+    /// Font size of the arrow glyphs.
+    pub font_size: f32,
+
+    /// Width and height of an arrow, in logical pixels.
+    pub size: f32,
+
+    /// Distance between an arrow and the edge of the window (or the inventory strip), in logical pixels.
+    pub margin: f32,
+}
+
+impl Default for NavigationUiStyle {
+    fn default() -> Self {
+        Self {
+            background: Color::rgba(0.0, 0.0, 0.0, 0.4),
+            hovered: Color::rgba(0.0, 0.0, 0.0, 0.7),
+            text: Color::WHITE,
+            font_size: 32.0,
+            size: 48.0,
+            margin: 16.0,
+        }
+    }
+}
+
+#[derive(Component)]
+struct NavigationArrow(SpotDirection);
+
+#[allow(clippy::needless_pass_by_value)]
+fn spawn_arrows(mut commands: Commands, style: Res<NavigationUiStyle>) {
+    let offset = Val::Px(-style.size / 2.0);
+
+    for direction in SpotDirection::ALL {
+        let mut layout = Style {
+            position_type: PositionType::Absolute,
+            width: Val::Px(style.size),
+            height: Val::Px(style.size),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        };
+
+        let glyph = match direction {
+            SpotDirection::Left => {
+                layout.left = Val::Px(style.margin);
+                layout.top = Val::Percent(50.0);
+                layout.margin.top = offset;
+                "<"
+            }
+            SpotDirection::Right => {
+                layout.right = Val::Px(style.margin);
+                layout.top = Val::Percent(50.0);
+                layout.margin.top = offset;
+                ">"
+            }
+            SpotDirection::Forward => {
+                layout.top = Val::Px(style.margin);
+                layout.left = Val::Percent(50.0);
+                layout.margin.left = offset;
+                "^"
+            }
+            SpotDirection::Back => {
+                layout.bottom = Val::Px(INVENTORY_HEIGHT + style.margin);
+                layout.left = Val::Percent(50.0);
+                layout.margin.left = offset;
+                "v"
+            }
+        };
+
+        commands
+            .spawn((
+                Name::new(format!("Navigation Arrow {direction:?}")),
+                NavigationArrow(direction),
+                ButtonBundle {
+                    style: layout,
+                    background_color: style.background.into(),
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+            ))
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section(glyph, TextStyle {
+                    font_size: style.font_size,
+                    color: style.text,
+                    ..default()
+                }));
+            });
+    }
+}
+
+/// Returns true if the cursor is over a visible arrow.
+fn over_arrow(arrows: &Query<(&Interaction, &Visibility), With<NavigationArrow>>) -> bool {
+    arrows.iter().any(|(interaction, visibility)| {
+        *visibility != Visibility::Hidden && *interaction != Interaction::None
+    })
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn block_edge_pan(
+    mut offset: ResMut<LookOffset>,
+    arrows: Query<(&Interaction, &Visibility), With<NavigationArrow>>,
+) {
+    if over_arrow(&arrows) {
+        offset.block_edges();
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn block_hovering(
+    mut hovering: ResMut<Hovering>,
+    arrows: Query<(&Interaction, &Visibility), With<NavigationArrow>>,
+) {
+    if over_arrow(&arrows) && hovering.entity.is_some() {
+        hovering.entity = None;
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn click_arrows(
+    mut navigate: EventWriter<Navigate>,
+    arrows: Query<(&NavigationArrow, &Interaction, &Visibility), Changed<Interaction>>,
+) {
+    for (arrow, interaction, visibility) in &arrows {
+        if *visibility != Visibility::Hidden && *interaction == Interaction::Pressed {
+            navigate.send(Navigate(arrow.0));
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn update_arrows(
+    style: Res<NavigationUiStyle>,
+    at_spot: Res<CurrentSpot>,
+    moving: Res<CameraMoving>,
+    examining: Option<Res<Examining>>,
+    neighbors: Query<&Neighbors>,
+    mut arrows: Query<(
+        &NavigationArrow,
+        &Interaction,
+        &mut Visibility,
+        &mut BackgroundColor,
+    )>,
+) {
+    let current = at_spot
+        .entity()
+        .filter(|_| !moving.is_moving() && examining.is_none())
+        .and_then(|spot| neighbors.get(spot).ok());
+
+    for (arrow, interaction, mut visibility, mut background) in &mut arrows {
+        let shown = current.is_some_and(|neighbors| neighbors.get(arrow.0).is_some());
+
+        let next = if shown {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+
+        if *visibility != next {
+            *visibility = next;
+        }
+
+        let color = if *interaction == Interaction::None {
+            style.background
+        } else {
+            style.hovered
+        };
+
+        if background.0 != color {
+            background.0 = color;
+        }
+    }
+}